@@ -0,0 +1,41 @@
+#ifndef GRADIENT_SLICE_H
+#define GRADIENT_SLICE_H
+
+/* C interface to gradient-slice, built with the `ffi` feature. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Opaque handle over a byte gradient, created by gradient_new. */
+typedef struct GradientHandle GradientHandle;
+
+/* One window: `len` bytes at `ptr`, spanning `start..end` of the input. */
+typedef struct GradientWindow {
+  const uint8_t *ptr;
+  size_t len;
+  size_t start;
+  size_t end;
+} GradientWindow;
+
+/* Creates a handle over the `len` bytes at `data`, which are borrowed
+ * and must stay valid and unchanged until gradient_free. Windows span
+ * up to `max_width` bytes, or every width when `max_width` is 0. */
+GradientHandle *gradient_new(const uint8_t *data, size_t len, size_t max_width);
+
+/* Writes the next window into `window` and returns true, or returns
+ * false once every window has been yielded. */
+bool gradient_next(GradientHandle *handle, GradientWindow *window);
+
+/* Releases a handle; NULL is ignored. */
+void gradient_free(GradientHandle *handle);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* GRADIENT_SLICE_H */
@@ -0,0 +1,170 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// `WindowAccumulator` maintains an aggregate of the elements of a
+/// window as they enter and leave it, see [Gradient::accumulate].
+pub trait WindowAccumulator<G> {
+    type Output;
+
+    /// `add` an element entering the window
+    fn add(&mut self, element: &G);
+
+    /// `remove` an element leaving the window, always one that was
+    /// previously added
+    fn remove(&mut self, element: &G);
+
+    /// `value` of the aggregate over the elements in the window
+    fn value(&self) -> Self::Output;
+}
+
+/// `Accumulate` yields every window of a [Gradient] along with the
+/// value of a [WindowAccumulator] over it.
+#[derive(Clone, Debug)]
+pub struct Accumulate<'a, G, A, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    empty: A,
+    first: Option<(usize, A)>,
+    current: Option<(Range<usize>, A)>,
+}
+impl<'a, G: 'a, A: WindowAccumulator<G> + Clone, S: Sliceable<G>> Iterator
+    for Accumulate<'a, G, A, S>
+{
+    type Item = (&'a [G], A::Output);
+
+    fn next(&mut self) -> Option<(&'a [G], A::Output)> {
+        let window = self.gradient.next()?;
+        let range = self.gradient.range();
+        let input = self.gradient.input.as_slice();
+        let accumulator = match (self.current.take(), &self.first) {
+            (Some((previous, mut accumulator)), _)
+                if previous.len() == range.len() && previous.start + 1 == range.start =>
+            {
+                accumulator.remove(&input[previous.start]);
+                accumulator.add(&input[range.end - 1]);
+                accumulator
+            }
+            (_, Some((width, first))) if range.start == 0 && *width + 1 == range.len() => {
+                let mut accumulator = first.clone();
+                accumulator.add(&input[range.end - 1]);
+                accumulator
+            }
+            _ => {
+                let mut accumulator = self.empty.clone();
+                window.iter().for_each(|element| accumulator.add(element));
+                accumulator
+            }
+        };
+        if range.start == 0 {
+            self.first = Some((range.len(), accumulator.clone()));
+        }
+        let value = accumulator.value();
+        self.current = Some((range, accumulator));
+        Some((window, value))
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `accumulate` pairs every window with the value of `empty`, a
+    /// [WindowAccumulator] over no elements, maintained incrementally:
+    /// sliding a window removes one element and adds one, and the
+    /// first window of each width grows the first window of the
+    /// previous width by one element. Decomposable aggregates are
+    /// then computed in `O(1)` per window instead of `O(width)`.
+    ///
+    /// ```
+    /// use gradient_slice::{Gradient, WindowAccumulator};
+    /// #[derive(Clone, Default)]
+    /// struct Sum(i64);
+    /// impl WindowAccumulator<i64> for Sum {
+    ///     type Output = i64;
+    ///     fn add(&mut self, element: &i64) {
+    ///         self.0 += element;
+    ///     }
+    ///     fn remove(&mut self, element: &i64) {
+    ///         self.0 -= element;
+    ///     }
+    ///     fn value(&self) -> i64 {
+    ///         self.0
+    ///     }
+    /// }
+    /// let sums = Gradient::new(vec![1, 2, 3])
+    ///     .accumulate(Sum::default())
+    ///     .map(|(_, sum)| sum)
+    ///     .collect::<Vec<i64>>();
+    /// assert_eq!(sums, vec![1, 2, 3, 3, 5, 6]);
+    /// ```
+    pub fn accumulate<A: WindowAccumulator<G> + Clone>(self, empty: A) -> Accumulate<'a, G, A, S> {
+        Accumulate {
+            gradient: self,
+            empty,
+            first: None,
+            current: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[derive(Clone, Default)]
+    struct Counted<'c> {
+        sum: u32,
+        operations: Option<&'c Cell<usize>>,
+    }
+    impl WindowAccumulator<u32> for Counted<'_> {
+        type Output = u32;
+
+        fn add(&mut self, element: &u32) {
+            self.sum += element;
+            self.operations
+                .inspect(|operations| operations.set(operations.get() + 1));
+        }
+
+        fn remove(&mut self, element: &u32) {
+            self.sum -= element;
+            self.operations
+                .inspect(|operations| operations.set(operations.get() + 1));
+        }
+
+        fn value(&self) -> u32 {
+            self.sum
+        }
+    }
+
+    #[test]
+    fn accumulate_matches_summing_each_window() {
+        let input = (1..=12u32).collect::<Vec<_>>();
+        let operations = Cell::new(0);
+        let empty = Counted {
+            sum: 0,
+            operations: Some(&operations),
+        };
+        let mut count = 0;
+        for (window, sum) in Gradient::from_slice(&input).accumulate(empty) {
+            assert_eq!(sum, window.iter().sum::<u32>());
+            count += 1;
+        }
+        assert_eq!(count, 78);
+        assert!(operations.get() <= 2 * count, "{}", operations.get());
+    }
+
+    #[test]
+    fn accumulate_from_an_advanced_gradient() {
+        let input = vec![4u32, 8, 15, 16, 23, 42];
+        let mut gradient = Gradient::with_max_width(input.clone(), Some(4));
+        gradient.nth(8);
+        let sums = gradient
+            .accumulate(Counted::default())
+            .map(|(window, sum)| (window.to_vec(), sum))
+            .collect::<Vec<_>>();
+        let expected = Gradient::with_max_width(input, Some(4))
+            .by_ref()
+            .skip(9)
+            .map(|window| (window.to_vec(), window.iter().sum()))
+            .collect::<Vec<_>>();
+        assert_eq!(sums, expected);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::Gradient;
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut, Range};
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+/// `Appender` appends elements to the input of an [AppendGradient]
+/// from any thread, see [AppendGradient::appender].
+#[derive(Debug)]
+pub struct Appender<G> {
+    sender: Sender<G>,
+}
+impl<G> Clone for Appender<G> {
+    fn clone(&self) -> Appender<G> {
+        Appender {
+            sender: self.sender.clone(),
+        }
+    }
+}
+impl<G> Appender<G> {
+    /// `push` appends `element` to the input, handing it back if the
+    /// [AppendGradient] was dropped.
+    pub fn push(&self, element: G) -> Result<(), G> {
+        self.sender.send(element).map_err(|error| error.0)
+    }
+}
+
+/// `AppendGradient` visits the windows of an input that keeps growing
+/// while it is iterated, see [Gradient::appendable].
+///
+/// Windows are visited in the order of a [Gradient], each at most
+/// once, from the position reached when elements are appended: those
+/// elements only extend the windows not visited yet. Windows of a
+/// width the gradient has moved past are not revisited, so appending
+/// extends the current width with the windows ending at the new
+/// elements, then every wider width.
+///
+/// Since the input may be reallocated as it grows, windows are lent
+/// out one at a time via [next_window](Self::next_window), which
+/// returns `None` when no window fits the elements appended so far
+/// and resumes once more are appended.
+///
+/// ```
+/// use gradient_slice::Gradient;
+/// let mut gradient = Gradient::new(vec![1, 2]).appendable();
+/// let appender = gradient.appender();
+/// assert_eq!(gradient.next_window(), Some(&[1][..]));
+/// appender.push(3).unwrap();
+/// let mut windows = Vec::new();
+/// while let Some(window) = gradient.next_window() {
+///     windows.push(window.to_vec());
+/// }
+/// assert_eq!(windows, vec![vec![2], vec![3], vec![1, 2], vec![2, 3], vec![1, 2, 3]]);
+/// ```
+#[derive(Debug)]
+pub struct AppendGradient<G> {
+    input: Vec<G>,
+    sender: Sender<G>,
+    receiver: Receiver<G>,
+    max_width: Option<usize>,
+    /// width of the next window
+    width: usize,
+    /// start of the next window
+    start: usize,
+    range: Range<usize>,
+}
+impl<G> AppendGradient<G> {
+    /// `appender` returns a handle appending elements to the input,
+    /// which can be cloned and sent to producer threads.
+    pub fn appender(&self) -> Appender<G> {
+        Appender {
+            sender: self.sender.clone(),
+        }
+    }
+
+    /// `next_window` returns the next window, or `None` if none fits
+    /// the input yet, after appending the elements pushed since the
+    /// last call.
+    pub fn next_window(&mut self) -> Option<&[G]> {
+        self.input.extend(self.receiver.try_iter());
+        let len = self.input.len();
+        let widest = self.max_width.map_or(len, |max_width| max_width.min(len));
+        if self.start + self.width > len {
+            if self.start == 0 || self.width >= widest {
+                return None;
+            }
+            (self.width, self.start) = (self.width + 1, 0);
+        }
+        if self.width > widest {
+            return None;
+        }
+        self.range = self.start..self.start + self.width;
+        self.start += 1;
+        Some(&self.input[self.range.clone()])
+    }
+
+    /// `input` appended so far, excluding elements pushed since the
+    /// last call to [next_window](Self::next_window)
+    pub fn input(&self) -> &[G] {
+        &self.input
+    }
+
+    /// `range` of the window most recently returned by
+    /// [next_window](Self::next_window).
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// `input_mut` borrows the input mutably, including the elements
+    /// pushed since the last call to [next_window](Self::next_window),
+    /// through an [InputMut] guard. No window can be outstanding while
+    /// it lives, and once it is dropped the gradient resumes from the
+    /// same position over the mutated input. If the input shrank past
+    /// that position, it resumes after the last window that still fits
+    /// instead, at a width no wider than the new input.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut gradient = Gradient::new(vec![1, 2, 3]).appendable();
+    /// gradient.next_window();
+    /// gradient.input_mut()[1] = 20;
+    /// assert_eq!(gradient.next_window(), Some(&[20][..]));
+    /// gradient.input_mut().truncate(2);
+    /// assert_eq!(gradient.next_window(), Some(&[1, 20][..]));
+    /// assert_eq!(gradient.next_window(), None);
+    /// ```
+    pub fn input_mut(&mut self) -> InputMut<'_, G> {
+        self.input.extend(self.receiver.try_iter());
+        InputMut { gradient: self }
+    }
+
+    /// `rewind` moves back before the first window, so the next call
+    /// to [next_window](Self::next_window) starts over.
+    pub fn rewind(&mut self) {
+        (self.width, self.start, self.range) = (1, 0, 0..0);
+    }
+}
+
+/// `InputMut` borrows the input of an [AppendGradient] mutably, see
+/// [AppendGradient::input_mut].
+#[derive(Debug)]
+pub struct InputMut<'g, G> {
+    gradient: &'g mut AppendGradient<G>,
+}
+impl<G> Deref for InputMut<'_, G> {
+    type Target = Vec<G>;
+
+    fn deref(&self) -> &Vec<G> {
+        &self.gradient.input
+    }
+}
+impl<G> DerefMut for InputMut<'_, G> {
+    fn deref_mut(&mut self) -> &mut Vec<G> {
+        &mut self.gradient.input
+    }
+}
+impl<G> Drop for InputMut<'_, G> {
+    /// moves the position back within the input if it shrank, and
+    /// forgets the range of the last window if it no longer fits
+    fn drop(&mut self) {
+        let gradient = &mut *self.gradient;
+        let len = gradient.input.len();
+        let widest = gradient
+            .max_width
+            .map_or(len, |max_width| max_width.min(len));
+        gradient.width = gradient.width.min(widest.max(1));
+        gradient.start = gradient.start.min(len + 1 - gradient.width);
+        if gradient.range.end > len {
+            gradient.range = 0..0;
+        }
+    }
+}
+
+impl<'a, G: 'a> Gradient<'a, G> {
+    /// `appendable` turns the [Gradient](Self) into an
+    /// [AppendGradient] whose input can grow while it is iterated,
+    /// resuming from the next window with the same `max_width`.
+    pub fn appendable(self) -> AppendGradient<G> {
+        let (width, start) = match self.wide {
+            true => (self.width, self.end + 1 - self.width),
+            false => (self.width + 1, 0),
+        };
+        let (sender, receiver) = channel();
+        AppendGradient {
+            input: self.input,
+            sender,
+            receiver,
+            max_width: self.max_width,
+            width,
+            start,
+            range: 0..0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn appended_elements_extend_windows_not_visited_yet() {
+        let mut gradient = Gradient::new(vec![1, 2, 3]);
+        gradient.nth(3);
+        let mut gradient = gradient.appendable();
+        let appender = gradient.appender();
+        let mut windows = Vec::new();
+        while let Some(window) = gradient.next_window() {
+            windows.push(window.to_vec());
+        }
+        assert_eq!(windows, vec![vec![2, 3], vec![1, 2, 3]]);
+        appender.push(4).unwrap();
+        windows.clear();
+        while let Some(window) = gradient.next_window() {
+            windows.push(window.to_vec());
+        }
+        assert_eq!(windows, vec![vec![2, 3, 4], vec![1, 2, 3, 4]]);
+    }
+
+    #[test]
+    fn appenders_clone_without_cloning_elements() {
+        #[derive(Debug, PartialEq)]
+        struct Reading(u8);
+        let mut gradient = Gradient::new(Vec::new()).appendable();
+        let appender = gradient.appender();
+        let clone = appender.clone();
+        drop(appender);
+        clone.push(Reading(1)).unwrap();
+        assert_eq!(gradient.next_window(), Some(&[Reading(1)][..]));
+    }
+
+    #[test]
+    fn mutating_the_input_keeps_the_position() {
+        let mut gradient = Gradient::with_max_width(vec![1, 2, 3, 4], Some(3)).appendable();
+        gradient.appender().push(5).unwrap();
+        for _ in 0..6 {
+            gradient.next_window();
+        }
+        assert_eq!(gradient.range(), 0..2);
+        {
+            let mut input = gradient.input_mut();
+            assert_eq!(*input, vec![1, 2, 3, 4, 5]);
+            input.retain(|element| element % 2 == 1);
+        }
+        assert_eq!(gradient.range(), 0..2);
+        let mut windows = Vec::new();
+        while let Some(window) = gradient.next_window() {
+            windows.push(window.to_vec());
+        }
+        assert_eq!(windows, vec![vec![3, 5], vec![1, 3, 5]]);
+        gradient.input_mut().clear();
+        assert_eq!(gradient.next_window(), None);
+        assert_eq!(gradient.range(), 0..0);
+        gradient.input_mut().extend([7, 8]);
+        gradient.rewind();
+        assert_eq!(gradient.next_window(), Some(&[7][..]));
+    }
+
+    #[test]
+    fn shrinking_below_the_current_width() {
+        for max_width in [None, Some(4)] {
+            let mut gradient = Gradient::with_max_width(vec![1, 2, 3, 4, 5, 6], max_width);
+            gradient.nth(16);
+            assert_eq!(gradient.range(), 1..5);
+            let mut gradient = gradient.appendable();
+            gradient.input_mut().truncate(2);
+            assert_eq!(gradient.next_window(), None);
+            assert_eq!(gradient.range(), 0..0);
+            gradient.input_mut().extend([3, 4]);
+            let mut windows = Vec::new();
+            while let Some(window) = gradient.next_window() {
+                windows.push(window.to_vec());
+            }
+            assert_eq!(
+                windows,
+                vec![
+                    vec![2, 3],
+                    vec![3, 4],
+                    vec![1, 2, 3],
+                    vec![2, 3, 4],
+                    vec![1, 2, 3, 4]
+                ]
+            );
+            gradient.input_mut().clear();
+            gradient.input_mut().push(7);
+            assert_eq!(gradient.next_window(), Some(&[7][..]));
+        }
+    }
+
+    #[test]
+    fn appending_from_a_producer_thread() {
+        let mut gradient = Gradient::with_max_width(Vec::new(), Some(3)).appendable();
+        let appender = gradient.appender();
+        let producer =
+            thread::spawn(move || (0..200u32).try_for_each(|element| appender.push(element)));
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut previous = None;
+        while previous != Some((3, 197)) {
+            assert!(Instant::now() < deadline, "stuck after {:?}", previous);
+            let Some(window) = gradient.next_window().map(<[u32]>::to_vec) else {
+                thread::yield_now();
+                continue;
+            };
+            let range = gradient.range();
+            assert_eq!(
+                window,
+                (range.start as u32..range.end as u32).collect::<Vec<_>>()
+            );
+            let next = Some((range.len(), range.start));
+            assert!(next > previous);
+            previous = next;
+        }
+        producer.join().unwrap().unwrap();
+        assert_eq!(gradient.next_window(), None);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::Gradient;
+use quickcheck::{Arbitrary, Gen};
+
+impl<G: Arbitrary> Arbitrary for Gradient<'static, G> {
+    /// a fresh [Gradient] over an arbitrary input, spanning up to an
+    /// arbitrary `max_width` between zero and past the input's length,
+    /// or unbounded
+    fn arbitrary(g: &mut Gen) -> Gradient<'static, G> {
+        let input = Vec::<G>::arbitrary(g);
+        let max_width = Option::<usize>::arbitrary(g).map(|width| width % (input.len() + 2));
+        Gradient::with_max_width(input, max_width)
+    }
+
+    /// lifts the width limit first, then narrows it, then shrinks the
+    /// input keeping the limit; shrunk gradients start over from the
+    /// first window
+    fn shrink(&self) -> Box<dyn Iterator<Item = Gradient<'static, G>>> {
+        let input = self.input.clone();
+        let max_width = self.max_width;
+        let relaxed = max_width.map(|_| Gradient::new(input.clone())).into_iter();
+        let narrowed = {
+            let input = input.clone();
+            max_width
+                .into_iter()
+                .flat_map(|width| width.shrink())
+                .map(move |width| Gradient::with_max_width(input.clone(), Some(width)))
+        };
+        let shrunk = input
+            .shrink()
+            .map(move |input| Gradient::with_max_width(input, max_width));
+        Box::new(relaxed.chain(narrowed).chain(shrunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::QuickCheck;
+
+    #[test]
+    fn arbitrary_gradients_yield_every_window() {
+        fn property(gradient: Gradient<'static, u8>) -> bool {
+            gradient.total() == gradient.clone().count()
+        }
+        QuickCheck::new()
+            .tests(200)
+            .quickcheck(property as fn(Gradient<'static, u8>) -> bool);
+    }
+
+    #[test]
+    fn shrinking_relaxes_the_width_limit_first() {
+        let gradient = Gradient::with_max_width(vec![7u8, 7, 7], Some(2));
+        let mut shrunk = gradient.shrink();
+        let relaxed = shrunk.next().unwrap();
+        assert_eq!((relaxed.input(), relaxed.max_width), (&[7, 7, 7][..], None));
+        assert!(shrunk.all(|gradient| gradient.len() <= 3 && gradient.max_width.is_some()));
+        assert!(Gradient::new(Vec::<u8>::new()).shrink().next().is_none());
+    }
+}
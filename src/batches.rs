@@ -0,0 +1,137 @@
+use crate::{Gradient, Sliceable, windows_narrower_than};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// `Batch` holds the ranges of up to `n` consecutive windows of a
+/// [Gradient], see [Gradient::batches].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Batch {
+    /// position of the batch, counted from the beginning of the gradient
+    pub index: usize,
+    /// ranges of the windows in the batch, in gradient order
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// `Batches` yields [Batch]es of window ranges, see [Gradient::batches].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Batches {
+    len: usize,
+    widest: usize,
+    size: usize,
+    index: usize,
+    width: usize,
+    start: usize,
+}
+impl Batches {
+    /// `seek` moves to the batch at `index` so that an interrupted
+    /// run can resume from the last batch it completed.
+    pub fn seek(&mut self, index: usize) {
+        self.index = index;
+        let window = index.saturating_mul(self.size);
+        let (mut low, mut high) = (1, self.widest + 1);
+        while low < high {
+            let width = low + (high - low).div_ceil(2);
+            if windows_narrower_than(self.len, width) <= window {
+                low = width;
+            } else {
+                high = width - 1;
+            }
+        }
+        self.width = low;
+        self.start = window.saturating_sub(windows_narrower_than(self.len, low));
+        if self.width > self.widest || self.start + self.width > self.len {
+            self.width = self.widest + 1;
+            self.start = 0;
+        }
+    }
+}
+impl Iterator for Batches {
+    type Item = Batch;
+
+    fn next(&mut self) -> Option<Batch> {
+        let mut ranges = Vec::new();
+        while ranges.len() < self.size && self.width <= self.widest {
+            ranges.push(self.start..self.start + self.width);
+            self.start += 1;
+            if self.start + self.width > self.len {
+                self.width += 1;
+                self.start = 0;
+            }
+        }
+        if ranges.is_empty() {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        Some(Batch { index, ranges })
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `batches` yields the ranges of the gradient's windows `n` at a
+    /// time. Batch boundaries are counted from the beginning of the
+    /// gradient, regardless of how far it has been iterated, so
+    /// [Batches::seek] can resume from any batch index.
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// ```
+    /// use gradient_slice::{Batch, Gradient};
+    /// let mut batches = Gradient::new(vec![1, 2, 3]).batches(4);
+    /// assert_eq!(
+    ///     batches.next(),
+    ///     Some(Batch { index: 0, ranges: vec![0..1, 1..2, 2..3, 0..2] })
+    /// );
+    /// assert_eq!(
+    ///     batches.next(),
+    ///     Some(Batch { index: 1, ranges: vec![1..3, 0..3] })
+    /// );
+    /// assert_eq!(batches.next(), None);
+    /// ```
+    pub fn batches(&self, n: usize) -> Batches {
+        assert!(n > 0, "n must be greater than zero");
+        Batches {
+            len: self.len(),
+            widest: self.widest(),
+            size: n,
+            index: 0,
+            width: 1,
+            start: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batches_match_gradient_ranges() {
+        let gradient = Gradient::with_max_width(" abc ".chars().collect(), Some(3));
+        let mut iterated = gradient.clone();
+        let mut expected = Vec::new();
+        while iterated.next().is_some() {
+            expected.push(iterated.range());
+        }
+        let ranges = gradient
+            .batches(4)
+            .flat_map(|batch| batch.ranges)
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, expected);
+    }
+
+    #[test]
+    fn batches_seek_resumes_at_stable_boundaries() {
+        let gradient = Gradient::new(" abc ".chars().collect());
+        let all = gradient.batches(4).collect::<Vec<_>>();
+        assert_eq!(
+            all.iter().map(|b| b.ranges.len()).collect::<Vec<_>>(),
+            vec![4, 4, 4, 3]
+        );
+        for index in 0..=all.len() {
+            let mut batches = gradient.batches(4);
+            batches.seek(index);
+            assert_eq!(batches.collect::<Vec<_>>(), all[index..].to_vec());
+        }
+    }
+}
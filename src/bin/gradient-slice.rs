@@ -0,0 +1,253 @@
+//! `gradient-slice` prints every window of a file or of standard input,
+//! built with the `cli` feature.
+use clap::{Parser, ValueEnum};
+use std::fs;
+use std::io::{self, BufWriter, Read, Write};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// what the input is cut into before windowing
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Mode {
+    Bytes,
+    Chars,
+    Lines,
+    Words,
+}
+impl Mode {
+    /// separator rendered between the elements of a window
+    fn separator(self) -> &'static [u8] {
+        match self {
+            Mode::Bytes | Mode::Chars => b"",
+            Mode::Lines => b"\n",
+            Mode::Words => b" ",
+        }
+    }
+}
+
+/// order windows are printed in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Order {
+    /// narrowest first, as a `Gradient` yields them
+    Gradient,
+    /// widest first, then by start
+    WidestFirst,
+    /// by start, then narrowest first
+    ByStart,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// one window per line, escaped
+    Plain,
+    /// one JSON object per line with the window, its range and width
+    Json,
+    /// start, end, width and the escaped window, tab separated
+    Tsv,
+}
+
+/// Prints every window of the input, narrowest first by default.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Arguments {
+    /// file to read, standard input when omitted or `-`
+    path: Option<PathBuf>,
+    /// elements windows are made of
+    #[arg(long, value_enum, default_value_t = Mode::Bytes)]
+    mode: Mode,
+    /// narrowest window printed, in elements
+    #[arg(long, default_value_t = 1)]
+    min_width: usize,
+    /// widest window printed, in elements
+    #[arg(long)]
+    max_width: Option<usize>,
+    #[arg(long, value_enum, default_value_t = Order::Gradient)]
+    order: Order,
+    #[arg(long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+}
+
+/// `input` cut into the elements of `mode`; `text` is `input`
+/// decoded, lossily, for every mode but bytes
+fn elements<'i>(input: &'i [u8], text: &'i str, mode: Mode) -> Vec<&'i [u8]> {
+    match mode {
+        Mode::Bytes => input.chunks(1).collect(),
+        Mode::Chars => text
+            .char_indices()
+            .map(|(index, char)| &text.as_bytes()[index..index + char.len_utf8()])
+            .collect(),
+        Mode::Lines => text.lines().map(str::as_bytes).collect(),
+        Mode::Words => text.split_whitespace().map(str::as_bytes).collect(),
+    }
+}
+
+/// ranges of the windows over `len` elements, from `min_width` to
+/// `max_width` wide, in `order`
+fn ranges(
+    len: usize,
+    min_width: usize,
+    max_width: Option<usize>,
+    order: Order,
+) -> Box<dyn Iterator<Item = Range<usize>>> {
+    let min_width = min_width.max(1);
+    let widest = max_width.unwrap_or(len).min(len);
+    match order {
+        Order::Gradient => Box::new(
+            (min_width..=widest)
+                .flat_map(move |width| (0..=len - width).map(move |start| start..start + width)),
+        ),
+        Order::WidestFirst => Box::new(
+            (min_width..=widest)
+                .rev()
+                .flat_map(move |width| (0..=len - width).map(move |start| start..start + width)),
+        ),
+        Order::ByStart => Box::new((0..len).flat_map(move |start| {
+            (min_width..=widest.min(len - start)).map(move |width| start..start + width)
+        })),
+    }
+}
+
+/// `window` joined by `separator`, lossily decoded
+fn render(window: &[&[u8]], separator: &[u8]) -> String {
+    String::from_utf8_lossy(&window.join(separator)).into_owned()
+}
+
+/// `text` with backslashes, tabs and line breaks escaped, keeping one
+/// window per line
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            char => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+fn print(arguments: &Arguments, input: &[u8], output: &mut impl Write) -> io::Result<()> {
+    let text = String::from_utf8_lossy(input);
+    let elements = elements(input, &text, arguments.mode);
+    let separator = arguments.mode.separator();
+    for range in ranges(
+        elements.len(),
+        arguments.min_width,
+        arguments.max_width,
+        arguments.order,
+    ) {
+        let window = render(&elements[range.clone()], separator);
+        match arguments.format {
+            Format::Plain => writeln!(output, "{}", escape(&window))?,
+            Format::Tsv => writeln!(
+                output,
+                "{}\t{}\t{}\t{}",
+                range.start,
+                range.end,
+                range.len(),
+                escape(&window)
+            )?,
+            Format::Json => {
+                let line = serde_json::json!({
+                    "window": window,
+                    "start": range.start,
+                    "end": range.end,
+                    "width": range.len(),
+                });
+                writeln!(output, "{line}")?
+            }
+        }
+    }
+    output.flush()
+}
+
+fn main() -> ExitCode {
+    let arguments = Arguments::parse();
+    let input = match arguments.path.as_deref() {
+        Some(path) if path.as_os_str() != "-" => fs::read(path),
+        _ => {
+            let mut input = Vec::new();
+            io::stdin().read_to_end(&mut input).map(|_| input)
+        }
+    };
+    let result = input.and_then(|input| {
+        let mut output = BufWriter::new(io::stdout().lock());
+        print(&arguments, &input, &mut output)
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("gradient-slice: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gradient_slice::Gradient;
+
+    fn run(arguments: &[&str], input: &str) -> String {
+        let arguments = Arguments::try_parse_from(
+            std::iter::once("gradient-slice").chain(arguments.iter().copied()),
+        )
+        .unwrap();
+        let mut output = Vec::new();
+        print(&arguments, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn orders_cover_the_same_windows() {
+        let mut expected = ranges(5, 2, Some(4), Order::Gradient).collect::<Vec<_>>();
+        let mut gradient = Gradient::with_max_width(vec![(); 5], Some(4));
+        let yielded = std::iter::from_fn(|| gradient.next().map(|_| gradient.range()))
+            .filter(|range| range.len() >= 2)
+            .collect::<Vec<_>>();
+        assert_eq!(expected, yielded);
+        for order in [Order::WidestFirst, Order::ByStart] {
+            let mut found = ranges(5, 2, Some(4), order).collect::<Vec<_>>();
+            found.sort_by_key(|range| (range.len(), range.start));
+            expected.sort_by_key(|range| (range.len(), range.start));
+            assert_eq!(found, expected);
+        }
+        assert_eq!(ranges(3, 4, None, Order::WidestFirst).count(), 0);
+        assert_eq!(ranges(0, 1, None, Order::ByStart).count(), 0);
+    }
+
+    #[test]
+    fn modes_and_formats() {
+        assert_eq!(
+            run(&["--mode", "chars", "--min-width", "2"], "hé\n"),
+            "hé\né\\n\nhé\\n\n"
+        );
+        assert_eq!(
+            run(
+                &[
+                    "--mode",
+                    "words",
+                    "--format",
+                    "tsv",
+                    "--order",
+                    "widest-first"
+                ],
+                "a b"
+            ),
+            "0\t2\t2\ta b\n0\t1\t1\ta\n1\t2\t1\tb\n"
+        );
+        assert_eq!(
+            run(
+                &["--mode", "lines", "--format", "json", "--max-width", "1"],
+                "x\ny\n"
+            ),
+            "{\"end\":1,\"start\":0,\"width\":1,\"window\":\"x\"}\n\
+             {\"end\":2,\"start\":1,\"width\":1,\"window\":\"y\"}\n"
+        );
+        assert_eq!(run(&["--order", "by-start"], "ab"), "a\nab\nb\n");
+    }
+}
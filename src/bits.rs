@@ -0,0 +1,101 @@
+use bitvec::order::BitOrder;
+use bitvec::slice::BitSlice;
+use bitvec::store::BitStore;
+
+/// `BitGradient` yields the windows of a [BitSlice] in the order of a
+/// [Gradient](crate::Gradient), as `&BitSlice` sub-slices of the
+/// packed input instead of one `bool` per bit.
+///
+/// ```
+/// use bitvec::prelude::*;
+/// use gradient_slice::BitGradient;
+/// let header = bits![u8, Msb0; 1, 0, 1];
+/// let windows = BitGradient::new(header)
+///     .map(|window| window.iter().by_vals().collect::<Vec<bool>>())
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     windows,
+///     vec![
+///         vec![true], vec![false], vec![true],
+///         vec![true, false], vec![false, true],
+///         vec![true, false, true],
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct BitGradient<'b, T: BitStore, O: BitOrder> {
+    input: &'b BitSlice<T, O>,
+    max_width: Option<usize>,
+    width: usize,
+    start: usize,
+}
+impl<'b, T: BitStore, O: BitOrder> BitGradient<'b, T, O> {
+    pub fn new(input: &'b BitSlice<T, O>) -> BitGradient<'b, T, O> {
+        BitGradient::with_max_width(input, None)
+    }
+
+    /// `with_max_width` creates a [BitGradient](Self) that optionally
+    /// spans to a maximum window width in bits.
+    pub fn with_max_width(
+        input: &'b BitSlice<T, O>,
+        max_width: Option<usize>,
+    ) -> BitGradient<'b, T, O> {
+        BitGradient {
+            input,
+            max_width,
+            width: 1,
+            start: 0,
+        }
+    }
+}
+impl<'b, T: BitStore, O: BitOrder> Iterator for BitGradient<'b, T, O> {
+    type Item = &'b BitSlice<T, O>;
+
+    fn next(&mut self) -> Option<&'b BitSlice<T, O>> {
+        let widest = match self.max_width {
+            Some(max_width) => max_width.min(self.input.len()),
+            None => self.input.len(),
+        };
+        if self.width > widest {
+            return None;
+        }
+        let window = &self.input[self.start..self.start + self.width];
+        self.start += 1;
+        if self.start + self.width > self.input.len() {
+            self.width += 1;
+            self.start = 0;
+        }
+        Some(window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gradient;
+    use alloc::vec::Vec;
+    use bitvec::prelude::*;
+
+    #[test]
+    fn bit_gradient_matches_expanded_bools() {
+        let packed = 0xB4u8.view_bits::<Lsb0>();
+        let expanded = packed.iter().by_vals().collect::<Vec<bool>>();
+        let windows = BitGradient::with_max_width(packed, Some(5))
+            .map(|window| window.iter().by_vals().collect::<Vec<bool>>())
+            .collect::<Vec<_>>();
+        let bools = Gradient::with_max_width(expanded.clone(), Some(5))
+            .by_ref()
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        assert_eq!(windows, bools);
+    }
+
+    #[test]
+    fn bit_gradient_windows_borrow_the_packed_input() {
+        let packed = bits![u8, Msb0; 1; 12];
+        let widest = BitGradient::new(packed).last().unwrap();
+        assert_eq!(widest.len(), 12);
+        assert_eq!(widest.as_bitptr(), packed.as_bitptr());
+        assert_eq!(BitGradient::new(bits![u8, Msb0;]).next(), None);
+    }
+}
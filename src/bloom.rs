@@ -0,0 +1,131 @@
+use crate::rolling::{Rolling, element_hashes, splitmix, width_hashes};
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::ops::RangeInclusive;
+
+/// `BloomFilter` answers whether a window may have appeared in an
+/// input without rescanning it, see [Gradient::to_bloom_filter].
+///
+/// It never reports an inserted window as missing, but may report a
+/// window that was never inserted as present.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BloomFilter {
+    words: Vec<u64>,
+    bits: usize,
+    hashes: usize,
+}
+impl BloomFilter {
+    /// `new` creates an empty [BloomFilter](Self) of `bits` bits,
+    /// setting `hashes` bits per window.
+    ///
+    /// Panics if `bits` or `hashes` is zero.
+    pub fn new(bits: usize, hashes: usize) -> BloomFilter {
+        assert!(bits > 0, "bits must be greater than zero");
+        assert!(hashes > 0, "hashes must be greater than zero");
+        BloomFilter {
+            words: alloc::vec![0; bits.div_ceil(64)],
+            bits,
+            hashes,
+        }
+    }
+
+    /// `insert` a window
+    pub fn insert<G: Hash>(&mut self, window: &[G]) {
+        self.insert_hash(Rolling::new(&element_hashes(window)).hash());
+    }
+
+    /// `contains` tells whether `window` may have been inserted
+    pub fn contains<G: Hash>(&self, window: &[G]) -> bool {
+        let hash = Rolling::new(&element_hashes(window)).hash();
+        self.positions(hash)
+            .all(|bit| self.words[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    pub fn hashes(&self) -> usize {
+        self.hashes
+    }
+
+    fn insert_hash(&mut self, hash: u64) {
+        for bit in self.positions(hash) {
+            self.words[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// double hashing: `h1 + i * h2` for `i` in `0..hashes`
+    fn positions(&self, hash: u64) -> impl Iterator<Item = usize> + use<> {
+        let (first, second) = (splitmix(hash), splitmix(!hash) | 1);
+        let bits = self.bits as u64;
+        (0..self.hashes as u64)
+            .map(move |index| (first.wrapping_add(index.wrapping_mul(second)) % bits) as usize)
+    }
+}
+
+impl<'a, G: Hash + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `to_bloom_filter` inserts every window whose width is within
+    /// `widths` into a new [BloomFilter] of `bits` bits and `hashes`
+    /// hashes per window, and returns it.
+    ///
+    /// Windows are hashed with rolling hashes, in `O(1)` per window.
+    ///
+    /// Panics if `bits` or `hashes` is zero.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let firmware = Gradient::new(b"\x7fELF\x02\x01\x01\x00".to_vec());
+    /// let filter = firmware.to_bloom_filter(2..=4, 1 << 12, 3);
+    /// assert!(filter.contains(b"ELF"));
+    /// assert!(filter.contains(b"\x7fE"));
+    /// assert!(!filter.contains(b"MZ"));
+    /// ```
+    pub fn to_bloom_filter(
+        &self,
+        widths: RangeInclusive<usize>,
+        bits: usize,
+        hashes: usize,
+    ) -> BloomFilter {
+        let mut filter = BloomFilter::new(bits, hashes);
+        let elements = element_hashes(self.input.as_slice());
+        let widest = (*widths.end()).min(self.widest());
+        for width in (*widths.start()).max(1)..=widest {
+            for hash in width_hashes(&elements, width) {
+                filter.insert_hash(hash);
+            }
+        }
+        filter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bloom_filter_has_no_false_negatives() {
+        let input = (0..200u16).map(|value| value * 7 % 31).collect::<Vec<_>>();
+        let filter = Gradient::from_slice(&input).to_bloom_filter(1..=8, 1 << 16, 4);
+        for window in Gradient::with_max_width(input.clone(), Some(8)).by_ref() {
+            assert!(filter.contains(window));
+        }
+        let absent = (0..500u16)
+            .map(|value| [value + 100, value])
+            .filter(|window| filter.contains(window))
+            .count();
+        assert!(absent < 10, "{}", absent);
+    }
+
+    #[test]
+    fn bloom_filter_widths_and_insert() {
+        let gradient = Gradient::new(b"abc".to_vec());
+        let mut filter = gradient.to_bloom_filter(2..=2, 256, 2);
+        assert!(filter.contains(b"bc"));
+        assert!(!filter.contains(b"abc"));
+        filter.insert(b"abc");
+        assert!(filter.contains(b"abc"));
+        assert_eq!((filter.bits(), filter.hashes()), (256, 2));
+    }
+}
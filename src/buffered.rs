@@ -0,0 +1,137 @@
+use crate::Gradient;
+use alloc::vec::Vec;
+use core::iter::Fuse;
+use core::ops::Range;
+
+/// `BufferedGradient` yields the windows of elements pulled from an
+/// iterator in the order of a [Gradient], pulling each element only
+/// once the next window reaches it, see [Gradient::from_iter_buffered].
+///
+/// The buffer grows as elements are pulled, so like
+/// [ReceiverGradient](crate::ReceiverGradient) windows are lent out
+/// one at a time via [next_window](Self::next_window).
+#[derive(Clone, Debug)]
+pub struct BufferedGradient<G, I> {
+    source: Fuse<I>,
+    buffer: Vec<G>,
+    max_width: Option<usize>,
+    width: usize,
+    start: usize,
+    range: Range<usize>,
+}
+impl<G, I: Iterator<Item = G>> BufferedGradient<G, I> {
+    /// `with_max_width` creates a [BufferedGradient](Self) that
+    /// optionally spans to a maximum window width.
+    pub fn with_max_width(
+        source: impl IntoIterator<IntoIter = I>,
+        max_width: Option<usize>,
+    ) -> BufferedGradient<G, I> {
+        BufferedGradient {
+            source: source.into_iter().fuse(),
+            buffer: Vec::new(),
+            max_width,
+            width: 1,
+            start: 0,
+            range: 0..0,
+        }
+    }
+
+    /// `next_window` returns the next window, pulling elements from
+    /// the source until it is buffered, or `None` once no window fits
+    /// in the source's elements.
+    pub fn next_window(&mut self) -> Option<&[G]> {
+        loop {
+            if self
+                .max_width
+                .is_some_and(|max_width| self.width > max_width)
+            {
+                return None;
+            }
+            let end = self.start + self.width;
+            if self.fill(end) {
+                self.range = self.start..end;
+                self.start += 1;
+                return Some(&self.buffer[self.range.clone()]);
+            }
+            if self.start == 0 {
+                return None;
+            }
+            (self.width, self.start) = (self.width + 1, 0);
+        }
+    }
+
+    /// pulls elements until `len` are buffered, `false` if the source
+    /// runs out first
+    fn fill(&mut self, len: usize) -> bool {
+        while self.buffer.len() < len {
+            match self.source.next() {
+                Some(element) => self.buffer.push(element),
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// `buffered` elements pulled from the source so far
+    pub fn buffered(&self) -> &[G] {
+        &self.buffer
+    }
+
+    /// `range` of the window most recently lent
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+impl<'a, G: 'a> Gradient<'a, G> {
+    /// `from_iter_buffered` creates a [BufferedGradient] pulling
+    /// elements from `source` lazily, so that a consumer stopping
+    /// early never pays for the elements it did not reach.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut parsed = 0;
+    /// let source = "3 1 4 1 5".split(' ').map(|word| {
+    ///     parsed += 1;
+    ///     word.parse::<u8>().unwrap()
+    /// });
+    /// let mut gradient = Gradient::from_iter_buffered(source);
+    /// assert_eq!(gradient.next_window(), Some(&[3][..]));
+    /// assert_eq!(gradient.next_window(), Some(&[1][..]));
+    /// assert_eq!(gradient.buffered(), [3, 1]);
+    /// ```
+    pub fn from_iter_buffered<I: IntoIterator<Item = G>>(
+        source: I,
+    ) -> BufferedGradient<G, I::IntoIter> {
+        BufferedGradient::with_max_width(source, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn buffered_gradient_matches_collecting_first() {
+        for len in 0..5u8 {
+            for max_width in [None, Some(0), Some(2), Some(9)] {
+                let pulled = Cell::new(0);
+                let source = (0..len).inspect(|_| pulled.set(pulled.get() + 1));
+                let mut buffered = BufferedGradient::with_max_width(source, max_width);
+                let mut expected = Gradient::with_max_width((0..len).collect(), max_width);
+                while let Some(window) = buffered.next_window() {
+                    let width = window.len();
+                    assert_eq!(Some(window), expected.next());
+                    assert_eq!(buffered.range(), expected.range());
+                    assert_eq!(pulled.get(), buffered.buffered().len());
+                    if width == 1 {
+                        assert_eq!(pulled.get(), buffered.range().end);
+                    }
+                }
+                assert_eq!(expected.next(), None);
+                assert_eq!(buffered.next_window(), None);
+            }
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Type-state builder for [Gradient]s and [ReceiverGradient]s.
+//!
+//! Each setter can be called at most once and changes the type of the
+//! [GradientBuilder], so that configurations which would otherwise be
+//! rejected or misbehave at runtime fail to compile instead.
+//!
+//! A streaming source cannot be built without a `max_width`:
+//!
+//! ```compile_fail
+//! use gradient_slice::GradientBuilder;
+//! let (_, receiver) = std::sync::mpsc::channel::<u8>();
+//! let gradient = GradientBuilder::new().receiver(receiver).build();
+//! ```
+//!
+//! and neither `max_width` nor the order can be set twice:
+//!
+//! ```compile_fail
+//! use core::num::NonZeroUsize;
+//! use gradient_slice::GradientBuilder;
+//! let two = NonZeroUsize::new(2).unwrap();
+//! let gradient = GradientBuilder::new().input(vec![1]).max_width(two).max_width(two);
+//! ```
+//!
+//! ```compile_fail
+//! use gradient_slice::{GradientBuilder, StartAscending, WidthDescending};
+//! let gradient = GradientBuilder::new()
+//!     .input(vec![1])
+//!     .order::<WidthDescending>()
+//!     .order::<StartAscending>();
+//! ```
+#[cfg(feature = "std")]
+use crate::ReceiverGradient;
+use crate::{Gradient, Order, WidthAscending};
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+#[cfg(feature = "std")]
+use std::sync::mpsc::Receiver;
+
+/// `GradientBuilder` configures a gradient step by step, tracking its
+/// input in `I`, its width limit in `W` and its order in `O`.
+///
+/// ```
+/// use core::num::NonZeroUsize;
+/// use gradient_slice::GradientBuilder;
+/// let widths = GradientBuilder::new()
+///     .max_width(NonZeroUsize::new(2).unwrap())
+///     .slice(&[1, 2, 3])
+///     .build()
+///     .map(<[i32]>::len)
+///     .collect::<Vec<usize>>();
+/// assert_eq!(widths, vec![1, 1, 1, 2, 2]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GradientBuilder<I = NoInput, W = AnyWidth, O = DefaultOrder> {
+    input: I,
+    width: W,
+    order: O,
+}
+
+/// no input chosen yet
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoInput;
+
+/// an owned input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Owned<G>(Vec<G>);
+
+/// a borrowed input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Borrowed<'a, G>(&'a [G]);
+
+/// elements streamed from a channel
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Streamed<G>(Receiver<G>);
+
+/// no width limit, windows spanning up to the whole input
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnyWidth;
+
+/// windows spanning up to a non-zero width
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaxWidth(NonZeroUsize);
+
+/// no order chosen yet, building a gradient in [WidthAscending] order
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DefaultOrder;
+
+/// the order of a [ReceiverGradient], newest windows narrowest first
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StreamOrder;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::AnyWidth {}
+    impl Sealed for super::MaxWidth {}
+    impl Sealed for super::DefaultOrder {}
+    impl<O: crate::Order> Sealed for O {}
+}
+
+/// `WidthLimit` is implemented by the width states of a
+/// [GradientBuilder].
+pub trait WidthLimit: sealed::Sealed {
+    fn max_width(&self) -> Option<usize>;
+}
+impl WidthLimit for AnyWidth {
+    fn max_width(&self) -> Option<usize> {
+        None
+    }
+}
+impl WidthLimit for MaxWidth {
+    fn max_width(&self) -> Option<usize> {
+        Some(self.0.get())
+    }
+}
+
+/// `OrderState` is implemented by the order states of a
+/// [GradientBuilder], naming the [Order] they build.
+pub trait OrderState: sealed::Sealed {
+    type Order: Order;
+}
+impl OrderState for DefaultOrder {
+    type Order = WidthAscending;
+}
+impl<O: Order> OrderState for O {
+    type Order = O;
+}
+
+impl GradientBuilder {
+    pub fn new() -> GradientBuilder {
+        GradientBuilder::default()
+    }
+}
+impl<W, O> GradientBuilder<NoInput, W, O> {
+    /// `input` has the gradient own `input`.
+    pub fn input<G>(self, input: Vec<G>) -> GradientBuilder<Owned<G>, W, O> {
+        GradientBuilder {
+            input: Owned(input),
+            width: self.width,
+            order: self.order,
+        }
+    }
+
+    /// `slice` has the gradient borrow `input`.
+    pub fn slice<G>(self, input: &[G]) -> GradientBuilder<Borrowed<'_, G>, W, O> {
+        GradientBuilder {
+            input: Borrowed(input),
+            width: self.width,
+            order: self.order,
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl<W> GradientBuilder<NoInput, W> {
+    /// `receiver` has the gradient stream elements from `receiver`,
+    /// which requires a [max_width](GradientBuilder::max_width) to
+    /// [build](GradientBuilder::build), and leaves no order to choose.
+    pub fn receiver<G>(
+        self,
+        receiver: Receiver<G>,
+    ) -> GradientBuilder<Streamed<G>, W, StreamOrder> {
+        GradientBuilder {
+            input: Streamed(receiver),
+            width: self.width,
+            order: StreamOrder,
+        }
+    }
+}
+impl<I, O> GradientBuilder<I, AnyWidth, O> {
+    /// `max_width` limits the width of the windows.
+    pub fn max_width(self, max_width: NonZeroUsize) -> GradientBuilder<I, MaxWidth, O> {
+        GradientBuilder {
+            input: self.input,
+            width: MaxWidth(max_width),
+            order: self.order,
+        }
+    }
+}
+impl<I, W> GradientBuilder<I, W> {
+    /// `order` chooses the [Order] the windows are yielded in.
+    pub fn order<O: Order>(self) -> GradientBuilder<I, W, O> {
+        GradientBuilder {
+            input: self.input,
+            width: self.width,
+            order: O::default(),
+        }
+    }
+}
+impl<G, W: WidthLimit, O: OrderState> GradientBuilder<Owned<G>, W, O> {
+    pub fn build<'a>(self) -> Gradient<'a, G, Vec<G>, O::Order> {
+        Gradient {
+            max_width: self.width.max_width(),
+            ..Gradient::new(self.input.0)
+        }
+        .ordered()
+    }
+}
+impl<'a, G, W: WidthLimit, O: OrderState> GradientBuilder<Borrowed<'a, G>, W, O> {
+    pub fn build(self) -> Gradient<'a, G, &'a [G], O::Order> {
+        Gradient {
+            max_width: self.width.max_width(),
+            ..Gradient::from_slice(self.input.0)
+        }
+        .ordered()
+    }
+}
+#[cfg(feature = "std")]
+impl<G> GradientBuilder<Streamed<G>, MaxWidth, StreamOrder> {
+    pub fn build(self) -> ReceiverGradient<G> {
+        ReceiverGradient::new(self.input.0, self.width.0.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builders_match_constructors() {
+        let two = NonZeroUsize::new(2).unwrap();
+        let input = alloc::vec![1, 2, 3];
+        assert_eq!(
+            GradientBuilder::new().input(input.clone()).build(),
+            Gradient::new(input.clone())
+        );
+        assert_eq!(
+            GradientBuilder::new()
+                .input(input.clone())
+                .max_width(two)
+                .build(),
+            Gradient::with_max_width(input.clone(), Some(2))
+        );
+        assert_eq!(
+            GradientBuilder::new().slice(&input).build(),
+            Gradient::from_slice(&input)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn receiver_builder_streams_windows() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        [1, 2, 3]
+            .iter()
+            .for_each(|element| sender.send(*element).unwrap());
+        drop(sender);
+        let mut streamed = GradientBuilder::new()
+            .receiver(receiver)
+            .max_width(NonZeroUsize::new(2).unwrap())
+            .build();
+        let mut windows = 0;
+        while streamed.next_window().is_some() {
+            windows += 1;
+        }
+        assert_eq!(windows, 5);
+    }
+
+    #[test]
+    fn order_is_carried_into_the_gradient() {
+        let built = GradientBuilder::new()
+            .slice(b"abc")
+            .order::<crate::WidthDescending>()
+            .build();
+        assert_eq!(built, Gradient::from_slice(b"abc").ordered());
+        assert_eq!(
+            built.map(<[u8]>::len).collect::<Vec<_>>(),
+            alloc::vec![3, 2, 2, 1, 1, 1]
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+
+/// `Chain` merges the windows of two [Gradient]s width by width, see
+/// [Gradient::chain].
+#[derive(Clone, Debug)]
+pub struct Chain<'a, G, S = Vec<G>, T = Vec<G>> {
+    first: Gradient<'a, G, S>,
+    second: Gradient<'a, G, T>,
+}
+impl<'a, G: 'a, S: Sliceable<G>, T: Sliceable<G>> Iterator for Chain<'a, G, S, T> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        match (self.first.next_width(), self.second.next_width()) {
+            (Some(first), Some(second)) if second < first => self.second.next(),
+            (Some(_), _) => self.first.next(),
+            (None, _) => self.second.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first, second) = (self.first.size_hint(), self.second.size_hint());
+        let high = first
+            .1
+            .zip(second.1)
+            .and_then(|(first, second)| first.checked_add(second));
+        (first.0.saturating_add(second.0), high)
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `chain` yields the windows of both gradients in global width
+    /// order: every window of width 1 of this [Gradient](Self), then
+    /// of `other`, then those of width 2 and so on, so that windows of
+    /// several inputs stay aligned by width.
+    ///
+    /// It shadows [Iterator::chain]; call that explicitly to yield
+    /// every window of one gradient after the other's.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let windows = Gradient::from_slice(b"ab")
+    ///     .chain(Gradient::from_slice(b"xyz"))
+    ///     .collect::<Vec<&[u8]>>();
+    /// assert_eq!(
+    ///     windows,
+    ///     vec![&b"a"[..], b"b", b"x", b"y", b"z", b"ab", b"xy", b"yz", b"xyz"]
+    /// );
+    /// ```
+    pub fn chain<T: Sliceable<G>>(self, other: Gradient<'a, G, T>) -> Chain<'a, G, S, T> {
+        Chain {
+            first: self,
+            second: other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_sorts_concatenation_stably_by_width() {
+        let (first, second) = (b"banana".to_vec(), b"band".to_vec());
+        for (with, max_width) in [
+            (None, None),
+            (Some(2), None),
+            (Some(7), Some(3)),
+            (Some(1), Some(0)),
+        ] {
+            let first = Gradient {
+                max_width: with,
+                ..Gradient::from_slice(&first)
+            };
+            let second = Gradient {
+                max_width,
+                ..Gradient::from_slice(&second)
+            };
+            let mut expected = Iterator::chain(first.clone(), second.clone()).collect::<Vec<_>>();
+            expected.sort_by_key(|window| window.len());
+            let mut chained = first.chain(second);
+            assert_eq!(chained.size_hint(), (expected.len(), Some(expected.len())));
+            assert_eq!(chained.by_ref().collect::<Vec<_>>(), expected);
+            assert_eq!(chained.next(), None);
+        }
+    }
+
+    #[test]
+    fn chain_after_advancing() {
+        let mut first = Gradient::from_slice(&[1, 2, 3]);
+        first.nth(3);
+        let windows = first.chain(Gradient::from_slice(&[7])).collect::<Vec<_>>();
+        assert_eq!(windows, vec![&[7][..], &[2, 3], &[1, 2, 3]]);
+    }
+}
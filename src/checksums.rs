@@ -0,0 +1,96 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+/// `Checksums` yields every window of a [Gradient] along with its
+/// digest by a [Hasher], see [Gradient::checksums].
+#[derive(Clone, Debug)]
+pub struct Checksums<'a, G, H, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    prefix: Option<(usize, H)>,
+}
+impl<'a, G: Hash + 'a, H: Hasher + Clone + Default, S: Sliceable<G>> Iterator
+    for Checksums<'a, G, H, S>
+{
+    type Item = (&'a [G], u64);
+
+    fn next(&mut self) -> Option<(&'a [G], u64)> {
+        let window = self.gradient.next()?;
+        let range = self.gradient.range();
+        if range.start != 0 {
+            let mut hasher = H::default();
+            G::hash_slice(window, &mut hasher);
+            return Some((window, hasher.finish()));
+        }
+        let hasher = match self.prefix.take() {
+            Some((width, mut hasher)) if width + 1 == range.len() => {
+                G::hash_slice(&window[width..], &mut hasher);
+                hasher
+            }
+            _ => {
+                let mut hasher = H::default();
+                G::hash_slice(window, &mut hasher);
+                hasher
+            }
+        };
+        let digest = hasher.finish();
+        self.prefix = Some((range.len(), hasher));
+        Some((window, digest))
+    }
+}
+
+impl<'a, G: Hash + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `checksums` pairs every window with its digest by a fresh `H`,
+    /// such as `crc32fast::Hasher`, `xxhash_rust::xxh64::Xxh64` or any
+    /// other [Hasher], fed the window through [Hash::hash_slice], so
+    /// byte windows are written as one contiguous block.
+    ///
+    /// The first window of each width resumes the hasher state of the
+    /// first window of the previous width, which assumes digests only
+    /// depend on the concatenation of the bytes written, as for every
+    /// streaming checksum. Other windows are hashed from scratch.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let checksums = Gradient::new(b"abc".to_vec())
+    ///     .checksums::<crc32fast::Hasher>()
+    ///     .map(|(_, crc)| crc as u32)
+    ///     .collect::<Vec<u32>>();
+    /// assert_eq!(checksums[5], crc32fast::hash(b"abc"));
+    /// assert_eq!(checksums[4], crc32fast::hash(b"bc"));
+    /// ```
+    pub fn checksums<H: Hasher + Clone + Default>(self) -> Checksums<'a, G, H, S> {
+        Checksums {
+            gradient: self,
+            prefix: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xxhash_rust::xxh64::{Xxh64, xxh64};
+
+    #[test]
+    fn checksums_match_hashing_each_window() {
+        let input = b"content defined chunking".to_vec();
+        let checksums = Gradient::from_slice(&input)
+            .checksums::<Xxh64>()
+            .collect::<Vec<_>>();
+        assert_eq!(checksums.len(), Gradient::from_slice(&input).total());
+        for (window, digest) in checksums {
+            assert_eq!(digest, xxh64(window, 0));
+        }
+    }
+
+    #[test]
+    fn checksums_of_an_advanced_gradient() {
+        let input = b"abcdef".to_vec();
+        let mut gradient = Gradient::from_slice(&input);
+        gradient.nth(3);
+        for (window, crc) in gradient.checksums::<crc32fast::Hasher>() {
+            assert_eq!(crc as u32, crc32fast::hash(window));
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// `ChunkedWindow` is a logical window of input stored in chunks: the
+/// chunks it touches, with the first and last ones trimmed to the
+/// window, see [ChunkedGradient].
+#[derive(Debug)]
+pub struct ChunkedWindow<'c, G, C> {
+    chunks: &'c [C],
+    /// elements of the first chunk before the window
+    skip: usize,
+    len: usize,
+    _marker: PhantomData<&'c G>,
+}
+impl<G, C> Clone for ChunkedWindow<'_, G, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<G, C> Copy for ChunkedWindow<'_, G, C> {}
+impl<'c, G: 'c, C: AsRef<[G]>> ChunkedWindow<'c, G, C> {
+    /// `len` of the window in elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `segments` of the window, one contiguous slice per chunk it
+    /// touches, skipping empty chunks
+    pub fn segments(&self) -> impl Iterator<Item = &'c [G]> + 'c {
+        let (mut skip, mut remaining) = (self.skip, self.len);
+        self.chunks.iter().filter_map(move |chunk| {
+            let chunk = chunk.as_ref();
+            let skipped = skip.min(chunk.len());
+            let taken = (chunk.len() - skipped).min(remaining);
+            skip -= skipped;
+            remaining -= taken;
+            (taken > 0).then(|| &chunk[skipped..skipped + taken])
+        })
+    }
+
+    /// `as_slice` borrows the window when it lies within a single
+    /// chunk, which is the case for all but the windows straddling a
+    /// chunk boundary
+    pub fn as_slice(&self) -> Option<&'c [G]> {
+        let mut segments = self.segments();
+        match (segments.next(), segments.next()) {
+            (Some(segment), None) => Some(segment),
+            (None, _) => Some(&[]),
+            _ => None,
+        }
+    }
+
+    /// `iter` over the elements of the window
+    pub fn iter(&self) -> impl Iterator<Item = &'c G> + 'c {
+        self.segments().flatten()
+    }
+
+    /// `to_vec` copies the window into a contiguous `Vec`
+    pub fn to_vec(&self) -> Vec<G>
+    where
+        G: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// `to_cow` borrows the window when it lies within a single chunk
+    /// and copies it otherwise
+    pub fn to_cow(&self) -> Cow<'c, [G]>
+    where
+        G: Clone,
+    {
+        match self.as_slice() {
+            Some(slice) => Cow::Borrowed(slice),
+            None => Cow::Owned(self.to_vec()),
+        }
+    }
+}
+impl<G: PartialEq, C: AsRef<[G]>> PartialEq<[G]> for ChunkedWindow<'_, G, C> {
+    fn eq(&self, other: &[G]) -> bool {
+        self.len == other.len() && self.iter().eq(other)
+    }
+}
+
+/// `ChunkedGradient` yields the windows of input stored as a sequence
+/// of chunks, such as the leaves of a rope or a `Vec` of `Vec`s, in
+/// the order of a [Gradient](crate::Gradient) over the concatenated
+/// elements, without ever concatenating the chunks.
+///
+/// ```
+/// use gradient_slice::ChunkedGradient;
+/// let chunks = vec![b"ab".to_vec(), b"c".to_vec()];
+/// let windows = ChunkedGradient::new(&chunks)
+///     .map(|window| (window.to_vec(), window.as_slice().is_some()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     windows,
+///     vec![
+///         (b"a".to_vec(), true), (b"b".to_vec(), true), (b"c".to_vec(), true),
+///         (b"ab".to_vec(), true), (b"bc".to_vec(), false),
+///         (b"abc".to_vec(), false),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChunkedGradient<'c, G, C> {
+    chunks: &'c [C],
+    /// `ends[i]`: number of elements in `chunks[..=i]`
+    ends: Vec<usize>,
+    max_width: Option<usize>,
+    width: usize,
+    start: usize,
+    /// first chunk ending after `start`
+    first: usize,
+    /// first chunk ending at or after `start + width`
+    last: usize,
+    range: Range<usize>,
+    _marker: PhantomData<&'c G>,
+}
+impl<'c, G, C: AsRef<[G]>> ChunkedGradient<'c, G, C> {
+    pub fn new(chunks: &'c [C]) -> ChunkedGradient<'c, G, C> {
+        ChunkedGradient::with_max_width(chunks, None)
+    }
+
+    /// `with_max_width` creates a [ChunkedGradient](Self) that
+    /// optionally spans to a maximum window width.
+    pub fn with_max_width(chunks: &'c [C], max_width: Option<usize>) -> ChunkedGradient<'c, G, C> {
+        let ends = chunks
+            .iter()
+            .scan(0usize, |end, chunk| {
+                *end += chunk.as_ref().len();
+                Some(*end)
+            })
+            .collect::<Vec<_>>();
+        ChunkedGradient {
+            chunks,
+            ends,
+            max_width,
+            width: 1,
+            start: 0,
+            first: 0,
+            last: 0,
+            range: 0..0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// `len` of the concatenated input
+    pub fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `range` of the concatenated elements in the window most
+    /// recently yielded
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+impl<'c, G: 'c, C: AsRef<[G]>> Iterator for ChunkedGradient<'c, G, C> {
+    type Item = ChunkedWindow<'c, G, C>;
+
+    fn next(&mut self) -> Option<ChunkedWindow<'c, G, C>> {
+        let len = self.len();
+        let widest = match self.max_width {
+            Some(max_width) => max_width.min(len),
+            None => len,
+        };
+        if self.width > widest {
+            return None;
+        }
+        let (start, end) = (self.start, self.start + self.width);
+        if start == 0 {
+            self.first = 0;
+            self.last = 0;
+        }
+        while self.ends[self.first] <= start {
+            self.first += 1;
+        }
+        while self.ends[self.last] < end {
+            self.last += 1;
+        }
+        let before = self.ends[self.first] - self.chunks[self.first].as_ref().len();
+        self.range = start..end;
+        self.start += 1;
+        if self.start + self.width > len {
+            self.width += 1;
+            self.start = 0;
+        }
+        Some(ChunkedWindow {
+            chunks: &self.chunks[self.first..=self.last],
+            skip: start - before,
+            len: end - start,
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gradient;
+
+    #[test]
+    fn chunked_gradient_matches_concatenated_gradient() {
+        let chunks: [&[u8]; 6] = [b"abc", b"", b"d", b"efgh", b"", b"ij"];
+        let concatenated = chunks.concat();
+        for max_width in [None, Some(3)] {
+            let mut expected = Gradient::with_max_width(concatenated.clone(), max_width);
+            let mut windows = ChunkedGradient::with_max_width(&chunks, max_width);
+            while let Some(window) = windows.next() {
+                let next = expected.next().map(<[u8]>::to_vec);
+                assert_eq!(Some(window.to_vec()), next);
+                assert_eq!(windows.range(), expected.range());
+                assert!(window.segments().all(|segment| !segment.is_empty()));
+                assert!(window == concatenated[windows.range()]);
+                let within = chunks.iter().any(|chunk| {
+                    let chunk = chunk.as_ptr_range();
+                    let window = window.to_cow();
+                    chunk.contains(&window.as_ptr()) && !window.is_empty()
+                });
+                assert_eq!(window.as_slice().is_some(), within);
+            }
+            assert_eq!(expected.next(), None);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use arrow_array::{Array, ArrayRef};
+
+/// `ArrayGradient` yields the windows of an [Arrow](arrow_array) array
+/// in the order of a [Gradient](crate::Gradient), as zero-copy
+/// [ArrayRef] slices that share the buffers and validity bitmap of the
+/// original array.
+///
+/// ```
+/// use arrow_array::{Array, Int32Array};
+/// use gradient_slice::ArrayGradient;
+/// let column = Int32Array::from(vec![Some(1), None, Some(3)]);
+/// let windows = ArrayGradient::new(&column).collect::<Vec<_>>();
+/// assert_eq!(windows.len(), 6);
+/// assert_eq!(windows[1].null_count(), 1);
+///
+/// let valid = ArrayGradient::new(&column).valid_only().collect::<Vec<_>>();
+/// assert_eq!(valid.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct ArrayGradient<'a> {
+    array: &'a dyn Array,
+    max_width: Option<usize>,
+    nulls: Option<Vec<usize>>,
+    width: usize,
+    start: usize,
+}
+impl<'a> ArrayGradient<'a> {
+    pub fn new(array: &'a dyn Array) -> ArrayGradient<'a> {
+        ArrayGradient::with_max_width(array, None)
+    }
+
+    /// `with_max_width` creates an [ArrayGradient](Self) that
+    /// optionally spans to a maximum window width.
+    pub fn with_max_width(array: &'a dyn Array, max_width: Option<usize>) -> ArrayGradient<'a> {
+        ArrayGradient {
+            array,
+            max_width,
+            nulls: None,
+            width: 1,
+            start: 0,
+        }
+    }
+
+    /// `valid_only` skips every window that contains a null according
+    /// to the array's logical validity.
+    pub fn valid_only(mut self) -> ArrayGradient<'a> {
+        let mut nulls = Vec::with_capacity(self.array.len() + 1);
+        nulls.push(0);
+        let logical = self.array.logical_nulls();
+        for index in 0..self.array.len() {
+            let null = logical.as_ref().is_some_and(|bitmap| bitmap.is_null(index));
+            nulls.push(nulls[index] + usize::from(null));
+        }
+        self.nulls = Some(nulls);
+        self
+    }
+
+    fn next_range(&mut self) -> Option<(usize, usize)> {
+        let len = self.array.len();
+        let widest = match self.max_width {
+            Some(max_width) => max_width.min(len),
+            None => len,
+        };
+        if self.width > widest {
+            return None;
+        }
+        let window = (self.start, self.width);
+        self.start += 1;
+        if self.start + self.width > len {
+            self.width += 1;
+            self.start = 0;
+        }
+        Some(window)
+    }
+}
+impl Iterator for ArrayGradient<'_> {
+    type Item = ArrayRef;
+
+    fn next(&mut self) -> Option<ArrayRef> {
+        loop {
+            let (offset, length) = self.next_range()?;
+            if let Some(nulls) = &self.nulls {
+                if nulls[offset + length] != nulls[offset] {
+                    continue;
+                }
+            }
+            return Some(self.array.slice(offset, length));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gradient;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{Int64Array, StringArray};
+
+    #[test]
+    fn array_gradient_matches_gradient() {
+        let values = vec![5i64, 7, 9, 11];
+        let column = Int64Array::from(values.clone());
+        let windows = ArrayGradient::with_max_width(&column, Some(3))
+            .map(|window| window.as_primitive::<Int64Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        let expected = Gradient::with_max_width(values, Some(3))
+            .by_ref()
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        assert_eq!(windows, expected);
+    }
+
+    #[test]
+    fn array_gradient_slices_share_buffers() {
+        let column = StringArray::from(vec!["a", "bc", "def"]);
+        let widest = ArrayGradient::new(&column).last().unwrap();
+        let widest = widest.as_string::<i32>();
+        assert_eq!(widest.value_data().as_ptr(), column.value_data().as_ptr());
+        assert_eq!(widest.value(2), "def");
+    }
+
+    #[test]
+    fn valid_only_respects_validity_bitmap() {
+        let column = Int64Array::from(vec![Some(1), Some(2), None, Some(4), Some(5)]);
+        let windows = ArrayGradient::new(&column)
+            .valid_only()
+            .map(|window| window.as_primitive::<Int64Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            windows,
+            vec![vec![1], vec![2], vec![4], vec![5], vec![1, 2], vec![4, 5]]
+        );
+        let windows = ArrayGradient::new(&column).valid_only().collect::<Vec<_>>();
+        assert!(windows.iter().all(|window| window.null_count() == 0));
+    }
+}
@@ -0,0 +1,240 @@
+use crate::rolling::{element_hashes, width_hashes};
+use crate::{Gradient, Sliceable};
+use core::hash::Hash;
+use std::collections::{HashMap, HashSet};
+
+/// `Common` yields the distinct windows of one [Gradient] that are
+/// also windows of another, see [Gradient::common].
+pub struct Common<'s, G> {
+    left: &'s [G],
+    right: &'s [G],
+    left_elements: Vec<u64>,
+    right_elements: Vec<u64>,
+    widest: usize,
+    width: usize,
+    start: usize,
+    hashes: Vec<u64>,
+    right_starts: HashMap<u64, Vec<usize>>,
+    yielded: HashMap<u64, Vec<usize>>,
+}
+impl<'s, G: Eq> Common<'s, G> {
+    /// hashes the windows of the current width on both sides
+    fn prepare(&mut self) {
+        self.hashes.clear();
+        self.hashes
+            .extend(width_hashes(&self.left_elements, self.width));
+        self.right_starts.clear();
+        for (start, hash) in width_hashes(&self.right_elements, self.width).enumerate() {
+            self.right_starts.entry(hash).or_default().push(start);
+        }
+        self.yielded.clear();
+    }
+}
+impl<'s, G: Eq> Iterator for Common<'s, G> {
+    type Item = &'s [G];
+
+    fn next(&mut self) -> Option<&'s [G]> {
+        loop {
+            if self.width > self.widest {
+                return None;
+            }
+            if self.start == 0 {
+                self.prepare();
+            }
+            if self.start + self.width > self.left.len() {
+                self.width += 1;
+                self.start = 0;
+                continue;
+            }
+            let (start, width) = (self.start, self.width);
+            self.start += 1;
+            let (left, right) = (self.left, self.right);
+            let window = &left[start..start + width];
+            let hash = self.hashes[start];
+            let occurs = |starts: Option<&Vec<usize>>, input: &[G]| {
+                starts.is_some_and(|starts| {
+                    starts
+                        .iter()
+                        .any(|start| input[*start..start + width] == *window)
+                })
+            };
+            if !occurs(self.right_starts.get(&hash), right) {
+                continue;
+            }
+            if occurs(self.yielded.get(&hash), left) {
+                continue;
+            }
+            self.yielded.entry(hash).or_default().push(start);
+            return Some(window);
+        }
+    }
+}
+
+impl<'a, G: Hash + Eq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `common` yields, in gradient order, every distinct window of
+    /// this [Gradient](Self) that is also a window of `other`, taking
+    /// the `max_width` of both into account.
+    ///
+    /// Windows are matched one width at a time by rolling hashes
+    /// confirmed by comparing contents, so only hashes and offsets of
+    /// a single width are held in memory.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let left = Gradient::new(b"abcd".to_vec());
+    /// let right = Gradient::new(b"xbcdab".to_vec());
+    /// let common = Gradient::common(&left, &right).collect::<Vec<&[u8]>>();
+    /// assert_eq!(
+    ///     common,
+    ///     vec![&b"a"[..], b"b", b"c", b"d", b"ab", b"bc", b"cd", b"bcd"]
+    /// );
+    /// ```
+    pub fn common<'s, T: Sliceable<G>>(&'s self, other: &'s Gradient<'_, G, T>) -> Common<'s, G> {
+        let (left, right) = (self.input.as_slice(), other.input.as_slice());
+        Common {
+            left,
+            right,
+            left_elements: element_hashes(left),
+            right_elements: element_hashes(right),
+            widest: self.widest().min(other.widest()),
+            width: 1,
+            start: 0,
+            hashes: Vec::new(),
+            right_starts: HashMap::new(),
+            yielded: HashMap::new(),
+        }
+    }
+
+    /// `longest_common_window` returns the widest window of this
+    /// [Gradient](Self) that is also a window of `other`, the first
+    /// in gradient order among equally wide ones.
+    ///
+    /// A common window implies its sub-windows are common as well, so
+    /// widths are binary searched.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let left = Gradient::new(b"the lazy dog".to_vec());
+    /// let right = Gradient::new(b"a lazy cat".to_vec());
+    /// assert_eq!(left.longest_common_window(&right), Some(&b" lazy "[..]));
+    /// ```
+    pub fn longest_common_window<T: Sliceable<G>>(
+        &self,
+        other: &Gradient<'_, G, T>,
+    ) -> Option<&[G]> {
+        let (left, right) = (self.input.as_slice(), other.input.as_slice());
+        let (left_elements, right_elements) = (element_hashes(left), element_hashes(right));
+        let first_common = |width: usize| {
+            let mut right_starts: HashMap<u64, Vec<usize>> = HashMap::new();
+            for (start, hash) in width_hashes(&right_elements, width).enumerate() {
+                right_starts.entry(hash).or_default().push(start);
+            }
+            width_hashes(&left_elements, width)
+                .enumerate()
+                .find(|(start, hash)| {
+                    let window = &left[*start..start + width];
+                    right_starts.get(hash).is_some_and(|starts| {
+                        starts
+                            .iter()
+                            .any(|start| right[*start..start + width] == *window)
+                    })
+                })
+                .map(|(start, _)| &left[start..start + width])
+        };
+        let (mut found, mut narrowest_failing) = (None, self.widest().min(other.widest()) + 1);
+        let mut widest_passing = 0;
+        while widest_passing + 1 < narrowest_failing {
+            let width = widest_passing + (narrowest_failing - widest_passing) / 2;
+            match first_common(width) {
+                Some(window) => {
+                    widest_passing = width;
+                    found = Some(window);
+                }
+                None => narrowest_failing = width,
+            }
+        }
+        found
+    }
+
+    /// `similarity` returns the Jaccard similarity of the sets of
+    /// windows of `width` of this [Gradient](Self) and `other`: the
+    /// number of distinct windows they share over the number of
+    /// distinct windows in either, from `0.0` to `1.0`.
+    ///
+    /// Windows are compared by rolling hash, so shingle sets are never
+    /// materialized. Two inputs without any window of `width` are
+    /// considered identical.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let left = Gradient::new(b"abcd".to_vec());
+    /// let right = Gradient::new(b"bcde".to_vec());
+    /// assert_eq!(Gradient::similarity(&left, &right, 2), 0.5);
+    /// assert_eq!(Gradient::similarity(&left, &left, 3), 1.0);
+    /// ```
+    pub fn similarity<T: Sliceable<G>>(&self, other: &Gradient<'_, G, T>, width: usize) -> f64 {
+        let shingles = |input: &[G]| {
+            if width == 0 {
+                return HashSet::new();
+            }
+            width_hashes(&element_hashes(input), width).collect::<HashSet<u64>>()
+        };
+        let (left, right) = (
+            shingles(self.input.as_slice()),
+            shingles(other.input.as_slice()),
+        );
+        let union = left.union(&right).count();
+        if union == 0 {
+            return 1.0;
+        }
+        left.intersection(&right).count() as f64 / union as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn common_matches_owned_window_sets() {
+        let (left, right) = (b"abracadabra".to_vec(), b"cadabrar".to_vec());
+        let right_windows = Gradient::from_slice(&right)
+            .map(Vec::from)
+            .collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+        let expected = Gradient::with_max_width(left.clone(), Some(5))
+            .by_ref()
+            .filter(|window| right_windows.contains(*window) && seen.insert(window.to_vec()))
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        let left = Gradient::with_max_width(left, Some(5));
+        let right = Gradient::new(right);
+        let common = left.common(&right).map(Vec::from).collect::<Vec<_>>();
+        assert_eq!(common, expected);
+        let longest = expected.iter().map(Vec::len).max().unwrap();
+        let first_longest = expected.iter().find(|window| window.len() == longest);
+        assert_eq!(
+            left.longest_common_window(&right),
+            first_longest.map(Vec::as_slice)
+        );
+    }
+
+    #[test]
+    fn similarity_of_shingle_sets() {
+        let left = Gradient::new(b"aaaa".to_vec());
+        let right = Gradient::new(b"aab".to_vec());
+        assert_eq!(left.similarity(&right, 2), 1.0 / 2.0);
+        assert_eq!(left.similarity(&right, 3), 0.0);
+        assert_eq!(left.similarity(&right, 5), 1.0);
+        assert_eq!(right.similarity(&left, 1), 1.0 / 2.0);
+    }
+
+    #[test]
+    fn nothing_in_common() {
+        let left = Gradient::new(vec![1, 2]);
+        let right = Gradient::new(vec![3]);
+        assert_eq!(left.common(&right).next(), None);
+        assert_eq!(left.longest_common_window(&right), None);
+    }
+}
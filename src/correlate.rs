@@ -0,0 +1,159 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+/// `Correlate` yields windows of a [Gradient] along with their dot
+/// product with a kernel, see [Gradient::correlate].
+#[derive(Clone, Debug)]
+pub struct Correlate<'s, G> {
+    input: &'s [G],
+    kernel: &'s [f64],
+    widest: usize,
+    width: usize,
+    start: usize,
+    /// `kernel` resampled to `width` elements
+    resampled: Vec<f64>,
+}
+impl<'s, G: Copy + Into<f64>> Iterator for Correlate<'s, G> {
+    type Item = (&'s [G], f64);
+
+    fn next(&mut self) -> Option<(&'s [G], f64)> {
+        loop {
+            if self.width > self.widest || self.kernel.is_empty() {
+                return None;
+            }
+            if self.start + self.width <= self.input.len() {
+                break;
+            }
+            self.width += 1;
+            self.start = 0;
+        }
+        if self.resampled.len() != self.width {
+            self.resampled = resample(self.kernel, self.width);
+        }
+        let window = &self.input[self.start..self.start + self.width];
+        self.start += 1;
+        let product = window
+            .iter()
+            .zip(&self.resampled)
+            .map(|(element, weight)| (*element).into() * weight)
+            .sum();
+        Some((window, product))
+    }
+}
+
+/// `kernel` linearly interpolated to `width` evenly spaced samples,
+/// first and last matching its own, unchanged when already `width`
+/// samples long
+fn resample(kernel: &[f64], width: usize) -> Vec<f64> {
+    let last = kernel.len() - 1;
+    (0..width)
+        .map(|index| {
+            let position = match width {
+                1 => last as f64 / 2.0,
+                _ => (index * last) as f64 / (width - 1) as f64,
+            };
+            let below = position as usize;
+            let above = (below + 1).min(last);
+            let fraction = position - below as f64;
+            kernel[below] + (kernel[above] - kernel[below]) * fraction
+        })
+        .collect()
+}
+
+impl<'a, G: Copy + Into<f64> + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `correlate` yields every window as wide as `kernel`, in order,
+    /// along with its dot product with `kernel`, e.g. as a matched
+    /// filter.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(vec![1u8, 2, 3, 4]);
+    /// let products = gradient.correlate(&[1.0, -1.0]).map(|(_, product)| product).collect::<Vec<_>>();
+    /// assert_eq!(products, vec![-1.0, -1.0, -1.0]);
+    /// ```
+    pub fn correlate<'k>(&'k self, kernel: &'k [f64]) -> Correlate<'k, G> {
+        self.correlate_resampled(kernel, kernel.len()..=kernel.len())
+    }
+
+    /// `correlate_resampled` yields every window whose width is within
+    /// `widths`, in gradient order, along with its dot product with
+    /// `kernel` linearly resampled to the window's width, so a shape
+    /// is matched at several scales.
+    ///
+    /// Resampling keeps the kernel's values, not its sum: a wider
+    /// window weighs more elements with the same coefficients.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(vec![0.0, 1.0, 0.5, 0.0]);
+    /// let products = gradient
+    ///     .correlate_resampled(&[0.0, 1.0], 3..=3)
+    ///     .map(|(_, product)| product)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(products, vec![1.0, 0.25]);
+    /// ```
+    pub fn correlate_resampled<'k>(
+        &'k self,
+        kernel: &'k [f64],
+        widths: RangeInclusive<usize>,
+    ) -> Correlate<'k, G> {
+        Correlate {
+            input: self.input.as_slice(),
+            kernel,
+            widest: (*widths.end()).min(self.widest()),
+            width: (*widths.start()).max(1),
+            start: 0,
+            resampled: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correlate_matches_dot_products() {
+        let input = [3i16, -1, 4, 1, -5, 9, 2, -6];
+        let kernel = [0.5, -2.0, 1.0];
+        let gradient = Gradient::from_slice(&input);
+        let products = gradient.correlate(&kernel).collect::<Vec<_>>();
+        assert_eq!(products.len(), input.len() - 2);
+        for ((window, product), expected) in products.into_iter().zip(input.windows(3)) {
+            assert_eq!(window, expected);
+            let dot = expected
+                .iter()
+                .zip(&kernel)
+                .map(|(a, b)| *a as f64 * b)
+                .sum::<f64>();
+            assert_eq!(product, dot);
+        }
+        assert_eq!(gradient.correlate(&[]).count(), 0);
+    }
+
+    #[test]
+    fn resample_interpolates_between_samples() {
+        assert_eq!(resample(&[0.0, 2.0, 4.0], 3), vec![0.0, 2.0, 4.0]);
+        assert_eq!(resample(&[0.0, 4.0], 5), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(resample(&[0.0, 1.0, 8.0], 1), vec![1.0]);
+        assert_eq!(resample(&[7.0], 3), vec![7.0; 3]);
+        let gradient = Gradient::with_max_width(vec![1.0f32; 5], Some(3));
+        let widths = gradient
+            .correlate_resampled(&[1.0], 2..=9)
+            .map(|(window, product)| (window.len(), product))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            widths,
+            vec![
+                (2, 2.0),
+                (2, 2.0),
+                (2, 2.0),
+                (2, 2.0),
+                (3, 3.0),
+                (3, 3.0),
+                (3, 3.0)
+            ]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{Gradient, Sliceable};
+use core::slice::Windows;
+
+/// `DeBruijnEdge` goes from the prefix of a window to its suffix,
+/// both one element narrower than the window.
+pub type DeBruijnEdge<'s, G> = (&'s [G], &'s [G]);
+
+/// `DeBruijnEdges` yields one de Bruijn graph edge per window of a
+/// fixed width, see [Gradient::de_bruijn_edges].
+#[derive(Clone, Debug)]
+pub struct DeBruijnEdges<'s, G> {
+    windows: Option<Windows<'s, G>>,
+}
+impl<'s, G> Iterator for DeBruijnEdges<'s, G> {
+    type Item = DeBruijnEdge<'s, G>;
+
+    fn next(&mut self) -> Option<DeBruijnEdge<'s, G>> {
+        let window = self.windows.as_mut()?.next()?;
+        Some((&window[..window.len() - 1], &window[1..]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint)
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `de_bruijn_edges` yields, for every window of width `k` in
+    /// order, the edge from its prefix to its suffix of width `k - 1`.
+    /// No edge is yielded when `k` is below two or wider than the
+    /// [Gradient](Self) goes.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"ACGT".to_vec());
+    /// let edges = gradient.de_bruijn_edges(3).collect::<Vec<_>>();
+    /// assert_eq!(edges, vec![(&b"AC"[..], &b"CG"[..]), (b"CG", b"GT")]);
+    /// ```
+    pub fn de_bruijn_edges(&self, k: usize) -> DeBruijnEdges<'_, G> {
+        DeBruijnEdges {
+            windows: (k >= 2 && k <= self.widest()).then(|| self.input.as_slice().windows(k)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, G: core::hash::Hash + Eq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `de_bruijn_edge_counts` returns every distinct edge of
+    /// [de_bruijn_edges](Self::de_bruijn_edges) along with its
+    /// multiplicity, in order of first occurrence.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"ababa".to_vec());
+    /// assert_eq!(
+    ///     gradient.de_bruijn_edge_counts(2),
+    ///     vec![((&b"a"[..], &b"b"[..]), 2), ((b"b", b"a"), 2)]
+    /// );
+    /// ```
+    pub fn de_bruijn_edge_counts(&self, k: usize) -> Vec<(DeBruijnEdge<'_, G>, usize)> {
+        let mut positions = std::collections::HashMap::new();
+        let mut counts: Vec<(DeBruijnEdge<'_, G>, usize)> = Vec::new();
+        for edge in self.de_bruijn_edges(k) {
+            let position = *positions.entry(edge).or_insert_with(|| {
+                counts.push((edge, 0));
+                counts.len() - 1
+            });
+            counts[position].1 += 1;
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn de_bruijn_edges_follow_windows_of_width_k() {
+        let input = b"GATTACA".to_vec();
+        let gradient = Gradient::from_slice(&input);
+        for k in 0..=input.len() + 1 {
+            let edges = gradient.de_bruijn_edges(k).collect::<Vec<_>>();
+            assert_eq!(edges.len(), gradient.de_bruijn_edges(k).size_hint().0);
+            if k < 2 || k > input.len() {
+                assert!(edges.is_empty());
+                continue;
+            }
+            assert_eq!(edges.len(), input.len() - k + 1);
+            for (start, (prefix, suffix)) in edges.into_iter().enumerate() {
+                assert_eq!(prefix, &input[start..start + k - 1]);
+                assert_eq!(suffix, &input[start + 1..start + k]);
+            }
+        }
+        let narrow = Gradient::with_max_width(input.clone(), Some(2));
+        assert_eq!(narrow.de_bruijn_edges(3).count(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn de_bruijn_edge_counts_sum_to_every_edge() {
+        let gradient = Gradient::new(b"AAAAACAAAA".to_vec());
+        let counts = gradient.de_bruijn_edge_counts(3);
+        assert_eq!(counts.iter().map(|(_, count)| count).sum::<usize>(), 8);
+        assert_eq!(counts[0], ((&b"AA"[..], &b"AA"[..]), 5));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+
+/// `Dedup` drops every window equal to the window yielded right
+/// before it, see [Gradient::dedup].
+#[derive(Clone, Debug)]
+pub struct Dedup<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    previous: Option<&'a [G]>,
+}
+impl<'a, G: PartialEq + 'a, S: Sliceable<G>> Iterator for Dedup<'a, G, S> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        loop {
+            let window = self.gradient.next()?;
+            if self.previous == Some(window) {
+                continue;
+            }
+            self.previous = Some(window);
+            return Some(window);
+        }
+    }
+}
+
+impl<'a, G: PartialEq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `dedup` drops a window when its contents equal the window
+    /// yielded immediately before it, which collapses the spans of
+    /// identical windows runs of repeated elements produce.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let windows = Gradient::new(b"aaab".to_vec())
+    ///     .dedup()
+    ///     .map(Vec::from)
+    ///     .collect::<Vec<Vec<u8>>>();
+    /// assert_eq!(
+    ///     windows,
+    ///     vec![
+    ///         b"a".to_vec(), b"b".to_vec(),
+    ///         b"aa".to_vec(), b"ab".to_vec(),
+    ///         b"aaa".to_vec(), b"aab".to_vec(),
+    ///         b"aaab".to_vec(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn dedup(self) -> Dedup<'a, G, S> {
+        Dedup {
+            gradient: self,
+            previous: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedup_only_drops_consecutive_duplicates() {
+        let input = b"aabaa";
+        let mut windows = Gradient::from_slice(input).collect::<Vec<_>>();
+        windows.dedup();
+        assert_eq!(
+            Gradient::from_slice(input).dedup().collect::<Vec<_>>(),
+            windows
+        );
+        assert_eq!(windows.iter().filter(|window| **window == b"a").count(), 2);
+    }
+
+    #[test]
+    fn dedup_compares_across_widths() {
+        let input = [7];
+        assert_eq!(Gradient::from_slice(&input).dedup().count(), 1);
+        let input = [7, 7];
+        assert_eq!(Gradient::from_slice(&input).dedup().count(), 2);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// `EmptyWindows` yields empty windows before the windows of a
+/// [Gradient], see [Gradient::with_empty_window] and
+/// [Gradient::with_empty_windows].
+#[derive(Clone, Debug)]
+pub struct EmptyWindows<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    boundaries: Range<usize>,
+}
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for EmptyWindows<'a, G, S> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        match self.boundaries.next() {
+            Some(boundary) => Some(self.gradient.slice(boundary..boundary)),
+            None => self.gradient.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.gradient.size_hint();
+        let empty = self.boundaries.len();
+        (
+            low.saturating_add(empty),
+            high.and_then(|high| high.checked_add(empty)),
+        )
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `with_empty_window` yields a single empty window, at the start
+    /// of the input, before the windows of the [Gradient](Self). It is
+    /// left out once the gradient has been advanced.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let windows = Gradient::new(vec![1, 2]).with_empty_window().map(Vec::from).collect::<Vec<_>>();
+    /// assert_eq!(windows, vec![vec![], vec![1], vec![2], vec![1, 2]]);
+    /// ```
+    pub fn with_empty_window(self) -> EmptyWindows<'a, G, S> {
+        EmptyWindows {
+            boundaries: self.unyielded(1),
+            gradient: self,
+        }
+    }
+
+    /// `with_empty_windows` yields the empty window at every boundary
+    /// between elements, from the start to the end of the input,
+    /// before the windows of the [Gradient](Self), unless the gradient
+    /// has already been advanced past them.
+    ///
+    /// Each empty window points into the input at its boundary, so
+    /// its position can be recovered like that of any other window.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let input = [1, 2];
+    /// let gradient = Gradient::from_slice(&input).with_empty_windows();
+    /// assert_eq!(gradient.size_hint(), (6, Some(6)));
+    /// let offsets = gradient
+    ///     .filter(|window| window.is_empty())
+    ///     .map(|window| window.as_ptr() as usize - input.as_ptr() as usize)
+    ///     .collect::<Vec<usize>>();
+    /// assert_eq!(offsets, vec![0, size_of::<i32>(), 2 * size_of::<i32>()]);
+    /// ```
+    pub fn with_empty_windows(self) -> EmptyWindows<'a, G, S> {
+        EmptyWindows {
+            boundaries: self.unyielded(self.len() + 1),
+            gradient: self,
+        }
+    }
+
+    /// the first `boundaries` boundaries, or none once the gradient
+    /// has yielded any window, as the empty ones come first
+    fn unyielded(&self, boundaries: usize) -> Range<usize> {
+        if self.yielded() > 0 {
+            return 0..0;
+        }
+        0..boundaries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_windows_come_before_width_one() {
+        for len in 0..4 {
+            let input = (0..len).collect::<Vec<u8>>();
+            let gradient = Gradient::from_slice(&input);
+            let once = gradient.clone().with_empty_window();
+            assert_eq!(once.size_hint().0, gradient.total() + 1);
+            assert_eq!(once.clone().count(), gradient.total() + 1);
+            let every = gradient.clone().with_empty_windows();
+            assert_eq!(every.size_hint().0, gradient.total() + len as usize + 1);
+            let windows = every.collect::<Vec<_>>();
+            assert!(
+                windows[..=len as usize]
+                    .iter()
+                    .all(|window| window.is_empty())
+            );
+            assert_eq!(&windows[len as usize + 1..], gradient.collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn advanced_gradients_yield_no_empty_windows() {
+        let input = [1, 2, 3];
+        let mut gradient = Gradient::from_slice(&input);
+        gradient.by_ref().take(4).for_each(drop);
+        let once = gradient.clone().with_empty_window();
+        assert_eq!(once.size_hint(), (2, Some(2)));
+        let every = gradient.clone().with_empty_windows();
+        assert_eq!(every.size_hint(), (2, Some(2)));
+        assert_eq!(every.collect::<Vec<_>>(), vec![&[2, 3][..], &[1, 2, 3][..]]);
+        assert_eq!(once.collect::<Vec<_>>(), gradient.collect::<Vec<_>>());
+    }
+}
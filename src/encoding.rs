@@ -0,0 +1,99 @@
+use crate::{Gradient, Sliceable};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// `Hex` yields every window of a byte [Gradient] as a lowercase
+/// hexadecimal `String`, see [Gradient::hex].
+#[cfg(feature = "hex")]
+#[derive(Clone, Debug)]
+pub struct Hex<'a, S = Vec<u8>> {
+    gradient: Gradient<'a, u8, S>,
+}
+#[cfg(feature = "hex")]
+impl<'a, S: Sliceable<u8>> Iterator for Hex<'a, S> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let window = self.gradient.next()?;
+        let mut encoded = String::with_capacity(window.len() * 2);
+        for byte in window {
+            encoded.push(DIGITS[(byte >> 4) as usize] as char);
+            encoded.push(DIGITS[(byte & 0xf) as usize] as char);
+        }
+        Some(encoded)
+    }
+}
+
+/// `Base64` yields every window of a byte [Gradient] as a padded
+/// standard base64 `String`, see [Gradient::base64].
+#[cfg(feature = "base64")]
+#[derive(Clone, Debug)]
+pub struct Base64<'a, S = Vec<u8>> {
+    gradient: Gradient<'a, u8, S>,
+}
+#[cfg(feature = "base64")]
+impl<'a, S: Sliceable<u8>> Iterator for Base64<'a, S> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        use base64::Engine;
+        let window = self.gradient.next()?;
+        Some(base64::engine::general_purpose::STANDARD.encode(window))
+    }
+}
+
+impl<'a, S: Sliceable<u8>> Gradient<'a, u8, S> {
+    /// `hex` encodes every window in lowercase hexadecimal, e.g. for
+    /// logging binary data.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let windows = Gradient::new(vec![0x0a, 0xff]).hex().collect::<Vec<_>>();
+    /// assert_eq!(windows, vec!["0a", "ff", "0aff"]);
+    /// ```
+    #[cfg(feature = "hex")]
+    pub fn hex(self) -> Hex<'a, S> {
+        Hex { gradient: self }
+    }
+
+    /// `base64` encodes every window in padded standard base64.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let windows = Gradient::new(b"hi".to_vec()).base64().collect::<Vec<_>>();
+    /// assert_eq!(windows, vec!["aA==", "aQ==", "aGk="]);
+    /// ```
+    #[cfg(feature = "base64")]
+    pub fn base64(self) -> Base64<'a, S> {
+        Base64 { gradient: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "hex")]
+    #[test]
+    fn hex_encodes_every_byte() {
+        let input = (0..=255u8).collect::<Vec<_>>();
+        let widest = Gradient::from_slice(&input).hex().last().unwrap();
+        let expected = input
+            .iter()
+            .map(|byte| alloc::format!("{byte:02x}"))
+            .collect::<String>();
+        assert_eq!(widest, expected);
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn base64_pads_every_window() {
+        let windows = Gradient::with_max_width(b"abcd".to_vec(), Some(4))
+            .base64()
+            .collect::<Vec<_>>();
+        assert_eq!(windows.len(), 10);
+        assert!(windows.iter().all(|window| window.len() % 4 == 0));
+        assert_eq!(windows.last().map(String::as_str), Some("YWJjZA=="));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Accumulate, Gradient, Sliceable, WindowAccumulator};
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// `Entropy` is a [WindowAccumulator] of the Shannon entropy, in bits
+/// per element, of the elements in a window.
+///
+/// It keeps a count per distinct element and the sum of
+/// `count * log2(count)`, so both adding and removing an element take
+/// `O(1)`: `entropy = log2(n) - sum / n`.
+#[derive(Clone, Debug)]
+pub struct Entropy<G> {
+    counts: HashMap<G, usize>,
+    len: usize,
+    sum: f64,
+}
+impl<G> Default for Entropy<G> {
+    fn default() -> Entropy<G> {
+        Entropy {
+            counts: HashMap::new(),
+            len: 0,
+            sum: 0.0,
+        }
+    }
+}
+
+fn weight(count: usize) -> f64 {
+    if count < 2 {
+        return 0.0;
+    }
+    let count = count as f64;
+    count * count.log2()
+}
+
+impl<G: Hash + Eq + Clone> WindowAccumulator<G> for Entropy<G> {
+    type Output = f64;
+
+    fn add(&mut self, element: &G) {
+        let count = self.counts.entry(element.clone()).or_default();
+        self.sum += weight(*count + 1) - weight(*count);
+        *count += 1;
+        self.len += 1;
+    }
+
+    fn remove(&mut self, element: &G) {
+        if let Some(count) = self.counts.get_mut(element) {
+            self.sum += weight(*count - 1) - weight(*count);
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(element);
+            }
+            self.len -= 1;
+        }
+    }
+
+    fn value(&self) -> f64 {
+        if self.len == 0 {
+            return 0.0;
+        }
+        let len = self.len as f64;
+        (len.log2() - self.sum / len).max(0.0)
+    }
+}
+
+impl<'a, G: Hash + Eq + Clone + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `entropies` pairs every window with its Shannon [Entropy] in
+    /// bits per element, maintained incrementally through
+    /// [accumulate](Self::accumulate). High-entropy windows of bytes
+    /// hint at packed or encrypted regions.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let entropies = Gradient::new(b"aabb".to_vec())
+    ///     .entropies()
+    ///     .map(|(_, entropy)| entropy)
+    ///     .collect::<Vec<f64>>();
+    /// assert_eq!(entropies[4..7], [0.0, 1.0, 0.0]);
+    /// assert_eq!(entropies[9], 1.0);
+    /// ```
+    pub fn entropies(self) -> Accumulate<'a, G, Entropy<G>, S> {
+        self.accumulate(Entropy::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entropy_of(window: &[u8]) -> f64 {
+        let mut counts = HashMap::new();
+        window
+            .iter()
+            .for_each(|byte| *counts.entry(byte).or_insert(0usize) += 1);
+        let len = window.len() as f64;
+        counts
+            .values()
+            .map(|count| {
+                let probability = *count as f64 / len;
+                -probability * probability.log2()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn entropies_match_computing_each_window() {
+        let input = b"\x00\x00\x00\x01\x7f\xff\x13\x37\x00\x00aaaabcd".to_vec();
+        for (window, entropy) in Gradient::from_slice(&input).entropies() {
+            assert!((entropy - entropy_of(window)).abs() < 1e-9, "{:?}", window);
+        }
+    }
+
+    #[test]
+    fn entropy_bounds() {
+        let uniform = (0..=255u8).collect::<Vec<_>>();
+        let (_, entropy) = Gradient::from_slice(&uniform).entropies().last().unwrap();
+        assert!((entropy - 8.0).abs() < 1e-9);
+        let mut accumulator = Entropy::default();
+        accumulator.add(&'x');
+        accumulator.remove(&'x');
+        assert_eq!(accumulator.value(), 0.0);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::Gradient;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+/// `Error` reports a [Gradient] configuration that would otherwise
+/// yield surprising windows, or none at all.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// a `max_width` of `Some(0)`, for which no window is yielded
+    ZeroMaxWidth,
+    /// a minimum width greater than the maximum width
+    MinGreaterThanMax { min: usize, max: usize },
+    /// a minimum width wider than the whole input
+    WidthExceedsLen { width: usize, len: usize },
+    /// a range that is reversed or reaches past the end of the input
+    InvalidRange {
+        start: usize,
+        end: usize,
+        len: usize,
+    },
+    /// more windows than fit in a `usize`
+    CountOverflow,
+    /// two inputs expected to be as long as each other that are not
+    LengthMismatch { left: usize, right: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ZeroMaxWidth => write!(f, "max_width of 0 yields no windows"),
+            Error::MinGreaterThanMax { min, max } => {
+                write!(f, "minimum width {min} is greater than maximum width {max}")
+            }
+            Error::WidthExceedsLen { width, len } => {
+                write!(f, "width {width} exceeds input length {len}")
+            }
+            Error::InvalidRange { start, end, len } => {
+                write!(f, "range {start}..{end} is invalid for input length {len}")
+            }
+            Error::CountOverflow => write!(f, "more windows than fit in a usize"),
+            Error::LengthMismatch { left, right } => {
+                write!(f, "input lengths {left} and {right} differ")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl<'a, G: 'a> Gradient<'a, G> {
+    /// `try_with_max_width` is [with_max_width](Self::with_max_width)
+    /// rejecting a `max_width` of `Some(0)`.
+    ///
+    /// ```
+    /// use gradient_slice::{Error, Gradient};
+    /// assert_eq!(Gradient::try_with_max_width(vec![1, 2], Some(0)), Err(Error::ZeroMaxWidth));
+    /// assert_eq!(Gradient::try_with_max_width(vec![1, 2], Some(1))?.count(), 2);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn try_with_max_width(
+        input: Vec<G>,
+        max_width: Option<usize>,
+    ) -> Result<Gradient<'a, G>, Error> {
+        if max_width == Some(0) {
+            return Err(Error::ZeroMaxWidth);
+        }
+        Ok(Gradient {
+            max_width,
+            ..Gradient::new(input)
+        })
+    }
+}
+
+/// Borrows the elements of a slice within a range, validating the
+/// range first.
+///
+/// ```
+/// use gradient_slice::{Error, Gradient};
+/// let input = [1, 2, 3, 4];
+/// let gradient = Gradient::try_from((&input[..], 1..3))?;
+/// assert_eq!(gradient.last(), Some(&[2, 3][..]));
+/// assert_eq!(
+///     Gradient::try_from((&input[..], 3..5)),
+///     Err(Error::InvalidRange { start: 3, end: 5, len: 4 })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+impl<'a, G: 'a> TryFrom<(&'a [G], Range<usize>)> for Gradient<'a, G, &'a [G]> {
+    type Error = Error;
+
+    fn try_from((input, range): (&'a [G], Range<usize>)) -> Result<Self, Error> {
+        match input.get(range.clone()) {
+            Some(input) => Ok(Gradient::from_slice(input)),
+            None => Err(Error::InvalidRange {
+                start: range.start,
+                end: range.end,
+                len: input.len(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reversed_range_is_invalid() {
+        let input = [1, 2, 3];
+        #[allow(clippy::reversed_empty_ranges)]
+        let range = 2..1;
+        assert_eq!(
+            Gradient::try_from((&input[..], range)),
+            Err(Error::InvalidRange {
+                start: 2,
+                end: 1,
+                len: 3
+            })
+        );
+        assert_eq!(
+            Gradient::try_from((&input[..], 3..3)).map(Iterator::count),
+            Ok(0)
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::Gradient;
+use alloc::vec::Vec;
+
+/// `GradientExt` constructs a [Gradient] borrowing a slice in place,
+/// without naming the type or cloning into a `Vec`; `Vec`s get it
+/// through deref.
+///
+/// ```
+/// use gradient_slice::GradientExt;
+/// let input = vec![1, 2, 3];
+/// let sums = input.gradient().map(|window| window.iter().sum::<i32>()).collect::<Vec<_>>();
+/// assert_eq!(sums, vec![1, 2, 3, 3, 5, 6]);
+/// assert_eq!(input[1..].gradient_with_max_width(Some(1)).count(), 2);
+/// ```
+pub trait GradientExt<G> {
+    /// `gradient` over every window
+    fn gradient(&self) -> Gradient<'_, G, &[G]>;
+
+    /// `gradient_with_max_width` over windows optionally spanning to a
+    /// maximum width
+    fn gradient_with_max_width(&self, max_width: Option<usize>) -> Gradient<'_, G, &[G]>;
+}
+impl<G> GradientExt<G> for [G] {
+    fn gradient(&self) -> Gradient<'_, G, &[G]> {
+        Gradient::from_slice(self)
+    }
+
+    fn gradient_with_max_width(&self, max_width: Option<usize>) -> Gradient<'_, G, &[G]> {
+        Gradient {
+            max_width,
+            ..Gradient::from_slice(self)
+        }
+    }
+}
+
+/// `StrGradientExt` constructs a [Gradient] over the bytes or the
+/// characters of a string.
+///
+/// ```
+/// use gradient_slice::StrGradientExt;
+/// let windows = "héy"
+///     .gradient_chars()
+///     .filter(|window| window.len() == 2)
+///     .map(|window| window.iter().collect::<String>())
+///     .collect::<Vec<_>>();
+/// assert_eq!(windows, vec!["hé", "éy"]);
+/// assert_eq!("héy".gradient_bytes().next(), Some(&b"h"[..]));
+/// ```
+pub trait StrGradientExt {
+    /// `gradient_bytes` borrows the UTF-8 bytes of the string
+    fn gradient_bytes(&self) -> Gradient<'_, u8, &[u8]>;
+
+    /// `gradient_chars` collects the characters of the string, which
+    /// windows of `char`s can't borrow
+    fn gradient_chars(&self) -> Gradient<'static, char>;
+}
+impl StrGradientExt for str {
+    fn gradient_bytes(&self) -> Gradient<'_, u8, &[u8]> {
+        Gradient::from_slice(self.as_bytes())
+    }
+
+    fn gradient_chars(&self) -> Gradient<'static, char> {
+        Gradient::new(self.chars().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_gradients_match_constructors() {
+        let input = [3u8, 1, 4, 1, 5];
+        let expected = Gradient::new(input.to_vec())
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            input.gradient().map(Vec::from).collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(input.to_vec().gradient().count(), expected.len());
+        assert_eq!(
+            input
+                .gradient_with_max_width(Some(2))
+                .map(Vec::from)
+                .collect::<Vec<_>>(),
+            Gradient::with_max_width(input.to_vec(), Some(2))
+                .map(Vec::from)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!("".gradient_chars().next(), None);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{Gradient, Sliceable};
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `min_window` returns the smallest window by [Ord], restricted
+    /// to windows of `width` when given, the first in gradient order
+    /// among equal ones.
+    ///
+    /// The window borrows the [Gradient](Self) rather than living for
+    /// `'a`, which is what `Iterator::min` would tie it to.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"banana".to_vec());
+    /// assert_eq!(gradient.min_window(None), Some(&b"a"[..]));
+    /// assert_eq!(gradient.min_window(Some(3)), Some(&b"ana"[..]));
+    /// ```
+    pub fn min_window(&self, width: Option<usize>) -> Option<&[G]>
+    where
+        G: Ord,
+    {
+        self.extreme_window_by(width, |window, best| window < best)
+    }
+
+    /// `max_window` returns the greatest window by [Ord], restricted
+    /// to windows of `width` when given, the first in gradient order
+    /// among equal ones.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"banana".to_vec());
+    /// assert_eq!(gradient.max_window(None), Some(&b"nana"[..]));
+    /// assert_eq!(gradient.max_window(Some(2)), Some(&b"na"[..]));
+    /// ```
+    pub fn max_window(&self, width: Option<usize>) -> Option<&[G]>
+    where
+        G: Ord,
+    {
+        self.extreme_window_by(width, |window, best| window > best)
+    }
+
+    /// `max_window_by_key` returns the window for which `key` is the
+    /// greatest, restricted to windows of `width` when given, the
+    /// first in gradient order among equal keys.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let readings = Gradient::new(vec![3, -1, 4, -1, 5, -9]);
+    /// let loudest = readings.max_window_by_key(Some(2), |window| {
+    ///     window.iter().map(|sample: &i32| sample.abs()).sum::<i32>()
+    /// });
+    /// assert_eq!(loudest, Some(&[5, -9][..]));
+    /// ```
+    pub fn max_window_by_key<K: Ord, F: FnMut(&[G]) -> K>(
+        &self,
+        width: Option<usize>,
+        mut key: F,
+    ) -> Option<&[G]> {
+        let mut best: Option<(K, &[G])> = None;
+        for window in self.windows_of(width) {
+            let candidate = key(window);
+            if best
+                .as_ref()
+                .is_none_or(|(greatest, _)| candidate > *greatest)
+            {
+                best = Some((candidate, window));
+            }
+        }
+        best.map(|(_, window)| window)
+    }
+
+    fn extreme_window_by<F: Fn(&[G], &[G]) -> bool>(
+        &self,
+        width: Option<usize>,
+        better: F,
+    ) -> Option<&[G]> {
+        self.windows_of(width).reduce(
+            |best, window| {
+                if better(window, best) { window } else { best }
+            },
+        )
+    }
+
+    fn windows_of(&self, width: Option<usize>) -> impl Iterator<Item = &[G]> {
+        self.rewound()
+            .filter(move |window| width.is_none_or(|width| window.len() == width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extremes_match_iterator_over_windows() {
+        let input = "gradient slice".chars().collect::<Vec<char>>();
+        let gradient = Gradient::with_max_width(input.clone(), Some(5));
+        for width in [None, Some(1), Some(3), Some(5)] {
+            let windows = Gradient::with_max_width(input.clone(), Some(5))
+                .by_ref()
+                .filter(|window| width.is_none_or(|width| window.len() == width))
+                .map(Vec::from)
+                .collect::<Vec<_>>();
+            let min = windows.iter().min().map(Vec::as_slice);
+            assert_eq!(gradient.min_window(width), min);
+            let max = windows.iter().max().map(Vec::as_slice);
+            assert_eq!(gradient.max_window(width), max);
+        }
+    }
+
+    #[test]
+    fn extremes_outside_max_width_or_empty() {
+        let gradient = Gradient::with_max_width(vec![1, 2, 3], Some(2));
+        assert_eq!(gradient.max_window(Some(3)), None);
+        assert_eq!(gradient.min_window(Some(0)), None);
+        assert_eq!(Gradient::new(Vec::<u8>::new()).max_window(None), None);
+    }
+
+    #[test]
+    fn max_window_by_key_keeps_first_of_equal_keys() {
+        let gradient = Gradient::new(vec![1, 2, 2, 1]);
+        let widest = gradient.max_window_by_key(None, |window| window.len().min(2));
+        assert_eq!(widest, Some(&[1, 2][..]));
+    }
+}
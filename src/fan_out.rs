@@ -0,0 +1,168 @@
+use crate::{Gradient, Sliceable};
+use crossbeam_channel::{Receiver, Sender};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// `FanOut` pushes owned windows of a [Gradient] into a bounded
+/// [crossbeam_channel] in chunks, see [Gradient::fan_out].
+///
+/// The channel disconnects once the `FanOut` and every clone of its
+/// [sender](Self::sender) are dropped, which ends `receiver.iter()`
+/// loops in the workers.
+pub struct FanOut<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    sender: Sender<Vec<Vec<G>>>,
+    chunk: usize,
+    stop: Arc<AtomicBool>,
+}
+
+/// `Shutdown` stops a [FanOut] from another thread, see
+/// [FanOut::shutdown].
+#[derive(Clone, Debug)]
+pub struct Shutdown {
+    stop: Arc<AtomicBool>,
+}
+impl Shutdown {
+    /// `stop` makes [FanOut::run] return before sending its next batch.
+    pub fn stop(&self) {
+        self.stop.store(true, Ordering::Release);
+    }
+}
+
+impl<'a, G: 'a, S> FanOut<'a, G, S> {
+    /// `sender` exposes the sending half of the channel. Clones of it
+    /// keep the channel connected until they are dropped as well.
+    pub fn sender(&self) -> &Sender<Vec<Vec<G>>> {
+        &self.sender
+    }
+
+    /// `shutdown` returns a handle that stops [run](Self::run).
+    pub fn shutdown(&self) -> Shutdown {
+        Shutdown {
+            stop: self.stop.clone(),
+        }
+    }
+}
+impl<'a, G: Clone + 'a, S: Sliceable<G>> FanOut<'a, G, S> {
+    /// `run` sends the remaining windows of the gradient, blocking
+    /// whenever the channel is full, and returns the number of windows
+    /// delivered.
+    ///
+    /// It returns early once every receiver has been dropped or a
+    /// [Shutdown] handle was stopped; in the latter case calling `run`
+    /// again resumes where it left off.
+    pub fn run(&mut self) -> usize {
+        let mut sent = 0;
+        while !self.stop.swap(false, Ordering::AcqRel) {
+            let batch = self
+                .gradient
+                .by_ref()
+                .take(self.chunk)
+                .map(<[G]>::to_vec)
+                .collect::<Vec<Vec<G>>>();
+            if batch.is_empty() {
+                break;
+            }
+            let windows = batch.len();
+            if self.sender.send(batch).is_err() {
+                break;
+            }
+            sent += windows;
+        }
+        sent
+    }
+}
+
+impl<'a, G: Clone + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `fan_out` creates a channel bounded to `capacity` batches of up
+    /// to `chunk` owned windows and returns the [FanOut] producer
+    /// alongside the receiver for worker threads to share.
+    ///
+    /// Panics if `chunk` is zero.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let (mut producer, receiver) = Gradient::new(vec![1, 2, 3]).fan_out(1, 2);
+    /// let workers = (0..2)
+    ///     .map(|_| {
+    ///         let receiver = receiver.clone();
+    ///         std::thread::spawn(move || receiver.iter().map(|batch| batch.len()).sum::<usize>())
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// drop(receiver);
+    /// assert_eq!(producer.run(), 6);
+    /// drop(producer);
+    /// let consumed = workers.into_iter().map(|worker| worker.join().unwrap()).sum::<usize>();
+    /// assert_eq!(consumed, 6);
+    /// ```
+    pub fn fan_out(
+        self,
+        capacity: usize,
+        chunk: usize,
+    ) -> (FanOut<'a, G, S>, Receiver<Vec<Vec<G>>>) {
+        assert!(chunk > 0, "chunk must be greater than zero");
+        let (sender, receiver) = crossbeam_channel::bounded(capacity);
+        (
+            FanOut {
+                gradient: self,
+                sender,
+                chunk,
+                stop: Arc::new(AtomicBool::new(false)),
+            },
+            receiver,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn fan_out_delivers_chunks_to_workers() {
+        let (mut producer, receiver) = Gradient::new(" abc ".chars().collect()).fan_out(2, 4);
+        let workers = (0..3)
+            .map(|_| {
+                let receiver = receiver.clone();
+                thread::spawn(move || receiver.iter().collect::<Vec<Vec<Vec<char>>>>())
+            })
+            .collect::<Vec<_>>();
+        drop(receiver);
+        let producer = thread::spawn(move || producer.run());
+        assert_eq!(producer.join().unwrap(), 15);
+        let mut batches = workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect::<Vec<_>>();
+        let mut sizes = batches.iter().map(Vec::len).collect::<Vec<_>>();
+        sizes.sort();
+        assert_eq!(sizes, vec![3, 4, 4, 4]);
+        batches.sort();
+        let mut windows = batches.into_iter().flatten().collect::<Vec<_>>();
+        windows.sort();
+        let mut expected = Gradient::new(" abc ".chars().collect())
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(windows, expected);
+    }
+
+    #[test]
+    fn fan_out_stops_when_receivers_are_gone() {
+        let (mut producer, receiver) = Gradient::new(" abc ".chars().collect()).fan_out(1, 4);
+        drop(receiver);
+        assert_eq!(producer.run(), 0);
+    }
+
+    #[test]
+    fn fan_out_stops_on_shutdown_and_resumes() {
+        let (mut producer, receiver) = Gradient::new(" abc ".chars().collect()).fan_out(8, 4);
+        producer.shutdown().stop();
+        assert_eq!(producer.run(), 0);
+        assert!(receiver.is_empty());
+        assert_eq!(producer.run(), 15);
+        drop(producer);
+        assert_eq!(receiver.iter().count(), 4);
+    }
+}
@@ -0,0 +1,144 @@
+//! C interface to byte gradients, declared in
+//! `include/gradient_slice.h`.
+//!
+//! A [GradientHandle] borrows the caller's buffer without copying it,
+//! so the buffer must outlive the handle and stay unchanged until
+//! [gradient_free].
+use crate::Gradient;
+use alloc::boxed::Box;
+use core::ptr;
+
+/// `GradientHandle` is the opaque C handle over a byte [Gradient],
+/// created by [gradient_new].
+pub struct GradientHandle {
+    gradient: Gradient<'static, u8, &'static [u8]>,
+}
+
+/// `GradientWindow` describes one window: `len` bytes at `ptr`,
+/// spanning `start..end` of the input.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GradientWindow {
+    pub ptr: *const u8,
+    pub len: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// `gradient_new` creates a handle over the `len` bytes at `data`,
+/// windows spanning up to `max_width` bytes, or every width when
+/// `max_width` is zero. Free it with [gradient_free].
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes, or may be null when
+/// `len` is zero, and they must stay valid and unchanged until the
+/// handle is freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gradient_new(
+    data: *const u8,
+    len: usize,
+    max_width: usize,
+) -> *mut GradientHandle {
+    let input: &'static [u8] = match data.is_null() || len == 0 {
+        true => &[],
+        false => unsafe { core::slice::from_raw_parts(data, len) },
+    };
+    let gradient = Gradient {
+        max_width: (max_width > 0).then_some(max_width),
+        ..Gradient::from_slice(input)
+    };
+    Box::into_raw(Box::new(GradientHandle { gradient }))
+}
+
+/// `gradient_next` writes the next window into `window` and returns
+/// `true`, or returns `false`, leaving `window` untouched, once every
+/// window has been yielded or when either pointer is null.
+///
+/// # Safety
+///
+/// `handle` must come from [gradient_new] and not have been freed,
+/// and `window` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gradient_next(
+    handle: *mut GradientHandle,
+    window: *mut GradientWindow,
+) -> bool {
+    if handle.is_null() || window.is_null() {
+        return false;
+    }
+    let gradient = unsafe { &mut (*handle).gradient };
+    let Some(next) = gradient.next() else {
+        return false;
+    };
+    let range = gradient.range();
+    unsafe {
+        ptr::write(
+            window,
+            GradientWindow {
+                ptr: next.as_ptr(),
+                len: next.len(),
+                start: range.start,
+                end: range.end,
+            },
+        )
+    };
+    true
+}
+
+/// `gradient_free` releases a handle; null is ignored.
+///
+/// # Safety
+///
+/// `handle` must be null or come from [gradient_new], and must not be
+/// used again afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gradient_free(handle: *mut GradientHandle) {
+    if !handle.is_null() {
+        drop(unsafe { Box::from_raw(handle) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn ffi_windows_match_gradient() {
+        let input = b"abcd".to_vec();
+        let mut windows = Vec::new();
+        unsafe {
+            let handle = gradient_new(input.as_ptr(), input.len(), 3);
+            let mut window = GradientWindow {
+                ptr: ptr::null(),
+                len: 0,
+                start: 0,
+                end: 0,
+            };
+            while gradient_next(handle, &mut window) {
+                let bytes = core::slice::from_raw_parts(window.ptr, window.len);
+                assert_eq!(bytes, &input[window.start..window.end]);
+                windows.push(bytes.to_vec());
+            }
+            assert!(!gradient_next(handle, &mut window));
+            gradient_free(handle);
+        }
+        let expected = Gradient::with_max_width(input.clone(), Some(3))
+            .map(<[u8]>::to_vec)
+            .collect::<Vec<_>>();
+        assert_eq!(windows, expected);
+    }
+
+    #[test]
+    fn ffi_tolerates_null() {
+        unsafe {
+            let handle = gradient_new(ptr::null(), 0, 0);
+            let mut window = core::mem::MaybeUninit::uninit();
+            assert!(!gradient_next(handle, window.as_mut_ptr()));
+            assert!(!gradient_next(ptr::null_mut(), window.as_mut_ptr()));
+            gradient_free(handle);
+            gradient_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// `Verdict` of a [filter_windows](Gradient::filter_windows) predicate
+/// on a window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    /// yield the window
+    Keep,
+    /// skip the window
+    Skip,
+    /// skip the window and every wider window at the same start,
+    /// without calling the predicate on them
+    SkipWider,
+}
+impl From<bool> for Verdict {
+    fn from(keep: bool) -> Verdict {
+        match keep {
+            true => Verdict::Keep,
+            false => Verdict::Skip,
+        }
+    }
+}
+
+/// `FilterWindows` yields the windows of a [Gradient] a predicate
+/// keeps, see [Gradient::filter_windows].
+#[derive(Clone, Debug)]
+pub struct FilterWindows<'a, G, F, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    predicate: F,
+    /// `blocked[start]`: narrowest width skipped with
+    /// [Verdict::SkipWider] at `start`
+    blocked: Vec<usize>,
+    skipped: usize,
+}
+impl<'a, G, F, S> FilterWindows<'a, G, F, S> {
+    /// `range` of the window most recently yielded, or considered.
+    pub fn range(&self) -> Range<usize> {
+        self.gradient.start..self.gradient.end
+    }
+
+    /// `skipped` counts the windows skipped by [Verdict::SkipWider]
+    /// hints without calling the predicate.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+impl<'a, G: 'a, V: Into<Verdict>, F: FnMut(&[G]) -> V, S: Sliceable<G>> Iterator
+    for FilterWindows<'a, G, F, S>
+{
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        loop {
+            let window = self.gradient.next()?;
+            let start = self.gradient.start();
+            if window.len() >= self.blocked[start] {
+                self.skipped += 1;
+                continue;
+            }
+            match (self.predicate)(window).into() {
+                Verdict::Keep => return Some(window),
+                Verdict::Skip => {}
+                Verdict::SkipWider => self.blocked[start] = window.len(),
+            }
+        }
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `filter_windows` yields only the windows `predicate` keeps.
+    ///
+    /// Unlike [Iterator::filter], the predicate returns a [Verdict], or
+    /// a `bool`, and may answer [Verdict::SkipWider] to let the
+    /// [Gradient](Self) skip every wider window at the same start
+    /// without calling it again; [skipped](FilterWindows::skipped)
+    /// counts those.
+    ///
+    /// ```
+    /// use gradient_slice::{Gradient, Verdict};
+    /// let input = b"ab\ncd";
+    /// let mut lines = Gradient::from_slice(input).filter_windows(|window: &[u8]| {
+    ///     match window.last() {
+    ///         Some(b'\n') => Verdict::SkipWider,
+    ///         _ => Verdict::Keep,
+    ///     }
+    /// });
+    /// let windows = lines.by_ref().collect::<Vec<_>>();
+    /// assert_eq!(windows, vec![&b"a"[..], b"b", b"c", b"d", b"ab", b"cd"]);
+    /// assert_eq!(lines.skipped(), 6);
+    /// ```
+    pub fn filter_windows<V: Into<Verdict>, F: FnMut(&[G]) -> V>(
+        self,
+        predicate: F,
+    ) -> FilterWindows<'a, G, F, S> {
+        FilterWindows {
+            blocked: alloc::vec![usize::MAX; self.len()],
+            gradient: self,
+            predicate,
+            skipped: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_windows_with_bool_matches_filter() {
+        let input = (0..9u8).collect::<Vec<_>>();
+        let even = |window: &[u8]| window.iter().map(|value| *value as u32).sum::<u32>() % 2 == 0;
+        let expected = Gradient::from_slice(&input)
+            .filter(|window| even(window))
+            .collect::<Vec<_>>();
+        let mut filtered = Gradient::from_slice(&input).filter_windows(even);
+        assert_eq!(filtered.by_ref().collect::<Vec<_>>(), expected);
+        assert_eq!(filtered.skipped(), 0);
+    }
+
+    #[test]
+    fn skip_wider_stops_calling_the_predicate_at_that_start() {
+        let input = [1u32, 1, 9, 1, 1];
+        let mut calls = 0;
+        let mut filtered = Gradient::from_slice(&input).filter_windows(|window: &[u32]| {
+            calls += 1;
+            match window.iter().sum::<u32>() {
+                sum if sum > 5 => Verdict::SkipWider,
+                _ => Verdict::Keep,
+            }
+        });
+        let kept = filtered.by_ref().map(<[u32]>::len).collect::<Vec<_>>();
+        assert_eq!(kept, vec![1, 1, 1, 1, 2, 2]);
+        let skipped = filtered.skipped();
+        drop(filtered);
+        assert_eq!(skipped, 6);
+        assert_eq!(calls + skipped, Gradient::from_slice(&input).total());
+    }
+}
@@ -0,0 +1,265 @@
+use core::f32::consts::PI;
+use core::ops::{Range, RangeInclusive};
+
+/// `WindowFunction` is multiplied sample by sample into every frame
+/// yielded by [Frames].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WindowFunction {
+    /// leaves samples untouched
+    #[default]
+    Rectangular,
+    /// `0.5 - 0.5 cos(2πn / (N - 1))`
+    Hann,
+    /// `0.54 - 0.46 cos(2πn / (N - 1))`
+    Hamming,
+}
+impl WindowFunction {
+    /// `coefficient` of sample `n` in a frame of `width` samples.
+    pub fn coefficient(self, n: usize, width: usize) -> f32 {
+        if width < 2 {
+            return 1.0;
+        }
+        let phase = (2.0 * PI * n as f32 / (width - 1) as f32).cos();
+        match self {
+            WindowFunction::Rectangular => 1.0,
+            WindowFunction::Hann => 0.5 - 0.5 * phase,
+            WindowFunction::Hamming => 0.54 - 0.46 * phase,
+        }
+    }
+}
+
+/// `Frames` cuts audio samples into frames for spectrogram
+/// generation: for every width in `widths`, in ascending order, frames
+/// start every `hop` samples, and each one is multiplied by a
+/// [WindowFunction] into a buffer reused from frame to frame.
+///
+/// Like [GradientMut](crate::GradientMut), the buffer is lent out one
+/// frame at a time via [next_frame](Self::next_frame).
+///
+/// ```
+/// use gradient_slice::{Frames, WindowFunction};
+/// let samples = [1.0; 8];
+/// let mut frames = Frames::new(&samples, 4..=4, 2).window_function(WindowFunction::Hann);
+/// let mut starts = Vec::new();
+/// while let Some(frame) = frames.next_frame() {
+///     assert_eq!(frame.len(), 4);
+///     assert_eq!((frame[0], frame[3]), (0.0, 0.0));
+///     starts.push(frames.range().start);
+/// }
+/// assert_eq!(starts, vec![0, 2, 4]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Frames<'s> {
+    samples: &'s [f32],
+    widths: RangeInclusive<usize>,
+    hop: usize,
+    function: WindowFunction,
+    width: usize,
+    start: usize,
+    range: Range<usize>,
+    coefficients: Vec<f32>,
+    buffer: Vec<f32>,
+}
+impl<'s> Frames<'s> {
+    /// `new` frames `samples` with every width in `widths`, a fixed
+    /// width being `n..=n`, starting a frame every `hop` samples.
+    ///
+    /// Panics if `hop` is zero.
+    pub fn new(samples: &'s [f32], widths: RangeInclusive<usize>, hop: usize) -> Frames<'s> {
+        assert!(hop > 0, "hop must be greater than zero");
+        Frames {
+            samples,
+            width: (*widths.start()).max(1),
+            widths,
+            hop,
+            function: WindowFunction::Rectangular,
+            start: 0,
+            range: 0..0,
+            coefficients: Vec::new(),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// `window_function` multiplied into every frame, rectangular by
+    /// default.
+    pub fn window_function(mut self, function: WindowFunction) -> Frames<'s> {
+        self.function = function;
+        self.coefficients.clear();
+        self
+    }
+
+    /// `next_frame` returns the next windowed frame, or `None` once
+    /// every width has been framed.
+    pub fn next_frame(&mut self) -> Option<&[f32]> {
+        loop {
+            if self.width > *self.widths.end() || self.width > self.samples.len() {
+                return None;
+            }
+            if self.start + self.width <= self.samples.len() {
+                break;
+            }
+            self.width += 1;
+            self.start = 0;
+        }
+        self.range = self.start..self.start + self.width;
+        self.start += self.hop;
+        if self.coefficients.len() != self.width {
+            let (function, width) = (self.function, self.width);
+            self.coefficients.clear();
+            self.coefficients
+                .extend((0..width).map(|n| function.coefficient(n, width)));
+        }
+        self.buffer.clear();
+        self.buffer.extend(
+            self.samples[self.range.clone()]
+                .iter()
+                .zip(&self.coefficients)
+                .map(|(sample, coefficient)| sample * coefficient),
+        );
+        Some(&self.buffer)
+    }
+
+    /// `range` of the samples in the frame most recently returned by
+    /// [next_frame](Self::next_frame).
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+/// `OverlapAdd` reconstructs a signal from processed [Frames]:
+/// every frame added is summed into the samples its range covers.
+///
+/// [normalized](Self::normalized) also divides every sample by the
+/// sum of the [WindowFunction] coefficients applied to it, so adding
+/// frames unchanged gives back the framed samples wherever they were
+/// weighted at all.
+///
+/// ```
+/// use gradient_slice::{Frames, OverlapAdd, WindowFunction};
+/// let samples = [0.5, 1.0, -2.0, 4.0, 0.25, 3.0];
+/// let mut frames = Frames::new(&samples, 3..=3, 1).window_function(WindowFunction::Hamming);
+/// let mut output = OverlapAdd::new(samples.len(), WindowFunction::Hamming);
+/// while let Some(frame) = frames.next_frame() {
+///     let processed = frame.to_vec();
+///     output.add(frames.range(), &processed);
+/// }
+/// let output = output.normalized();
+/// assert!(output.iter().zip(&samples).all(|(a, b)| (a - b).abs() < 1e-5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct OverlapAdd {
+    function: WindowFunction,
+    sums: Vec<f32>,
+    weights: Vec<f32>,
+}
+impl OverlapAdd {
+    /// `new` reconstructs `len` samples from frames windowed by
+    /// `function`.
+    pub fn new(len: usize, function: WindowFunction) -> OverlapAdd {
+        OverlapAdd {
+            function,
+            sums: vec![0.0; len],
+            weights: vec![0.0; len],
+        }
+    }
+
+    /// `add` sums `frame` into the samples within `range`, as
+    /// returned by [Frames::range].
+    ///
+    /// Panics if `frame` isn't as wide as `range` or if `range` ends
+    /// past the reconstructed length.
+    pub fn add(&mut self, range: Range<usize>, frame: &[f32]) {
+        assert_eq!(
+            range.len(),
+            frame.len(),
+            "frame must be as wide as its range"
+        );
+        let width = frame.len();
+        for (n, (sum, weight)) in self.sums[range.clone()]
+            .iter_mut()
+            .zip(&mut self.weights[range])
+            .enumerate()
+        {
+            *sum += frame[n];
+            *weight += self.function.coefficient(n, width);
+        }
+    }
+
+    /// `sums` of the frames added over every sample
+    pub fn sums(self) -> Vec<f32> {
+        self.sums
+    }
+
+    /// `normalized` sums, each divided by the sum of the window
+    /// coefficients applied to its sample, `0.0` for samples no frame
+    /// weighted
+    pub fn normalized(self) -> Vec<f32> {
+        self.sums
+            .into_iter()
+            .zip(self.weights)
+            .map(|(sum, weight)| {
+                if weight > f32::EPSILON {
+                    sum / weight
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_with_hop_one_match_gradient() {
+        let samples = [0.5, -1.0, 0.25, 2.0];
+        let mut frames = Frames::new(&samples, 1..=4, 1);
+        let mut windows = crate::Gradient::from_slice(&samples);
+        while let Some(frame) = frames.next_frame() {
+            assert_eq!(Some(frame), windows.next());
+        }
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn gradient_widths_with_hop() {
+        let samples = [0.0; 10];
+        let mut frames = Frames::new(&samples, 3..=5, 3);
+        let mut ranges = Vec::new();
+        while frames.next_frame().is_some() {
+            ranges.push(frames.range());
+        }
+        assert_eq!(
+            ranges,
+            vec![0..3, 3..6, 6..9, 0..4, 3..7, 6..10, 0..5, 3..8]
+        );
+    }
+
+    #[test]
+    fn hamming_frame_is_symmetric_and_peaks_in_the_middle() {
+        let samples = [2.0; 5];
+        let mut frames = Frames::new(&samples, 5..=5, 1).window_function(WindowFunction::Hamming);
+        let frame = frames.next_frame().unwrap().to_vec();
+        assert!((frame[0] - 0.16).abs() < 1e-6);
+        assert!((frame[2] - 2.0).abs() < 1e-6);
+        assert!((frame[1] - frame[3]).abs() < 1e-6);
+        assert_eq!(frames.next_frame(), None);
+    }
+
+    #[test]
+    fn overlap_add_sums_and_normalizes() {
+        let samples = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut frames = Frames::new(&samples, 2..=3, 2);
+        let mut output = OverlapAdd::new(samples.len(), WindowFunction::Rectangular);
+        while let Some(frame) = frames.next_frame() {
+            let frame = frame.to_vec();
+            output.add(frames.range(), &frame);
+        }
+        assert_eq!(output.clone().sums(), vec![2.0, 4.0, 9.0, 8.0, 5.0]);
+        assert_eq!(output.normalized(), samples.to_vec());
+        let hann = OverlapAdd::new(3, WindowFunction::Hann);
+        assert_eq!(hann.normalized(), vec![0.0; 3]);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+
+/// `FuzzyMatching` yields the windows of a [Gradient] within an edit
+/// distance of a needle, along with that distance, see
+/// [Gradient::fuzzy_matching].
+#[derive(Clone, Debug)]
+pub struct FuzzyMatching<'s, G> {
+    input: &'s [G],
+    /// narrowest width that can match
+    narrowest: usize,
+    /// `distances[start * widths + width - narrowest]`: edit distance
+    /// of the window, `k + 1` when greater than `k`
+    distances: Vec<usize>,
+    widths: usize,
+    k: usize,
+    width: usize,
+    start: usize,
+}
+impl<'s, G> Iterator for FuzzyMatching<'s, G> {
+    type Item = (&'s [G], usize);
+
+    fn next(&mut self) -> Option<(&'s [G], usize)> {
+        while self.width < self.narrowest + self.widths {
+            if self.start + self.width > self.input.len() {
+                self.width += 1;
+                self.start = 0;
+                continue;
+            }
+            let start = self.start;
+            self.start += 1;
+            let distance = self.distances[start * self.widths + self.width - self.narrowest];
+            if distance <= self.k {
+                return Some((&self.input[start..start + self.width], distance));
+            }
+        }
+        None
+    }
+}
+
+/// edit distances between `needle` and every prefix of `text` no
+/// wider than `needle.len() + k`, computing only the diagonal band
+/// of the table within `k` of the main diagonal. Distances above `k`
+/// are clamped to `k + 1`.
+fn banded_distances<G: PartialEq>(needle: &[G], text: &[G], k: usize) -> Vec<usize> {
+    let far = k + 1;
+    let text = &text[..text.len().min(needle.len() + k)];
+    let mut previous = (0..=text.len()).map(|j| j.min(far)).collect::<Vec<_>>();
+    let mut current = alloc::vec![far; text.len() + 1];
+    for (i, expected) in needle.iter().enumerate().map(|(i, g)| (i + 1, g)) {
+        let (low, high) = (i.saturating_sub(k), (i + k).min(text.len()));
+        if low > high {
+            return alloc::vec![far; text.len() + 1];
+        }
+        current[low.saturating_sub(1)..=(high + 1).min(text.len())].fill(far);
+        for j in low..=high {
+            current[j] = if j == 0 {
+                i.min(far)
+            } else {
+                let substitution = previous[j - 1] + usize::from(text[j - 1] != *expected);
+                substitution
+                    .min(previous[j] + 1)
+                    .min(current[j - 1] + 1)
+                    .min(far)
+            };
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
+impl<'a, G: PartialEq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `fuzzy_matching` yields, in gradient order, every window whose
+    /// edit distance to `needle` is at most `k`, paired with that
+    /// distance.
+    ///
+    /// Only widths within `k` of the needle's can match. For each
+    /// start, one banded dynamic-programming pass over the next
+    /// `needle.len() + k` elements scores every such width at once, in
+    /// `O(k * (needle.len() + k))`, rather than aligning each window
+    /// from scratch.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"kitten sitting".to_vec());
+    /// let matches = gradient
+    ///     .fuzzy_matching(b"sitten", 1)
+    ///     .map(|(window, distance)| (String::from_utf8(window.to_vec()).unwrap(), distance))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     matches,
+    ///     vec![
+    ///         ("itten".to_string(), 1),
+    ///         ("kitten".to_string(), 1),
+    ///         ("sittin".to_string(), 1)
+    ///     ]
+    /// );
+    /// ```
+    pub fn fuzzy_matching(&self, needle: &[G], k: usize) -> FuzzyMatching<'_, G> {
+        let input = self.input.as_slice();
+        let narrowest = needle.len().saturating_sub(k).max(1);
+        let widest = (needle.len() + k).min(self.widest());
+        let widths = (widest + 1).saturating_sub(narrowest);
+        let mut distances = Vec::with_capacity(input.len() * widths);
+        for start in 0..input.len() {
+            let row = banded_distances(needle, &input[start..], k);
+            distances.extend(
+                (narrowest..narrowest + widths)
+                    .map(|width| row.get(width).copied().unwrap_or(k + 1)),
+            );
+        }
+        FuzzyMatching {
+            input,
+            narrowest,
+            distances,
+            widths,
+            k,
+            width: narrowest,
+            start: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levenshtein(left: &[u8], right: &[u8]) -> usize {
+        let mut row = (0..=right.len()).collect::<Vec<_>>();
+        for (i, a) in left.iter().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, b) in right.iter().enumerate() {
+                let above = row[j + 1];
+                row[j + 1] = (diagonal + usize::from(a != b))
+                    .min(above + 1)
+                    .min(row[j] + 1);
+                diagonal = above;
+            }
+        }
+        row[right.len()]
+    }
+
+    #[test]
+    fn fuzzy_matching_agrees_with_levenshtein() {
+        let input = b"the quick brown fox jumps over the lazy dog".to_vec();
+        for needle in [&b"the"[..], b"quack", b"fox", b"", b"lazy dogs"] {
+            for k in 0..3 {
+                for max_width in [None, Some(4)] {
+                    let gradient = Gradient::with_max_width(input.clone(), max_width);
+                    let expected = Gradient::from_slice(&input)
+                        .filter(|window| window.len() <= gradient.widest())
+                        .map(|window| (window, levenshtein(window, needle)))
+                        .filter(|(_, distance)| *distance <= k)
+                        .collect::<Vec<_>>();
+                    let found = gradient.fuzzy_matching(needle, k).collect::<Vec<_>>();
+                    assert_eq!(found, expected, "{:?} within {}", needle, k);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::Gradient;
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// `GapWindow` is a logical window of a gap buffer: the elements it
+/// spans before the gap and after it, see [GapGradient].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct GapWindow<'g, G> {
+    /// elements of the window before the gap
+    pub before: &'g [G],
+    /// elements of the window after the gap
+    pub after: &'g [G],
+}
+impl<G> Clone for GapWindow<'_, G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<G> Copy for GapWindow<'_, G> {}
+impl<'g, G> GapWindow<'g, G> {
+    /// `len` of the window in elements
+    pub fn len(&self) -> usize {
+        self.before.len() + self.after.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `as_slice` borrows the window unless it spans the gap
+    pub fn as_slice(&self) -> Option<&'g [G]> {
+        match (self.before, self.after) {
+            ([], after) => Some(after),
+            (before, []) => Some(before),
+            _ => None,
+        }
+    }
+
+    /// `iter` over the elements of the window, skipping the gap
+    pub fn iter(&self) -> impl Iterator<Item = &'g G> + 'g {
+        self.before.iter().chain(self.after)
+    }
+
+    /// `to_vec` copies the window into a contiguous `Vec`
+    pub fn to_vec(&self) -> Vec<G>
+    where
+        G: Clone,
+    {
+        [self.before, self.after].concat()
+    }
+
+    /// `to_cow` borrows the window unless it spans the gap, copying
+    /// it then
+    pub fn to_cow(&self) -> Cow<'g, [G]>
+    where
+        G: Clone,
+    {
+        match self.as_slice() {
+            Some(slice) => Cow::Borrowed(slice),
+            None => Cow::Owned(self.to_vec()),
+        }
+    }
+}
+impl<G: PartialEq> PartialEq<[G]> for GapWindow<'_, G> {
+    fn eq(&self, other: &[G]) -> bool {
+        self.len() == other.len() && self.iter().eq(other)
+    }
+}
+
+/// `GapGradient` yields the windows of a gap buffer, given as the
+/// elements before and after its gap, in the order of a [Gradient]
+/// over the buffer's contents, without closing the gap.
+///
+/// ```
+/// use gradient_slice::GapGradient;
+/// let (before, after) = (&b"ab"[..], &b"c"[..]);
+/// let windows = GapGradient::new(before, after)
+///     .map(|window| window.to_vec())
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     windows,
+///     vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec(), b"ab".to_vec(), b"bc".to_vec(), b"abc".to_vec()]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct GapGradient<'g, G> {
+    before: &'g [G],
+    after: &'g [G],
+    /// traverses the positions of the buffer's elements
+    positions: Gradient<'static, ()>,
+}
+impl<'g, G> GapGradient<'g, G> {
+    pub fn new(before: &'g [G], after: &'g [G]) -> GapGradient<'g, G> {
+        GapGradient::with_max_width(before, after, None)
+    }
+
+    /// `with_max_width` creates a [GapGradient](Self) that optionally
+    /// spans to a maximum window width.
+    pub fn with_max_width(
+        before: &'g [G],
+        after: &'g [G],
+        max_width: Option<usize>,
+    ) -> GapGradient<'g, G> {
+        GapGradient {
+            before,
+            after,
+            positions: Gradient::with_max_width(vec![(); before.len() + after.len()], max_width),
+        }
+    }
+
+    /// `len` of the buffer's contents
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// `range` of the buffer's contents in the window most recently
+    /// yielded
+    pub fn range(&self) -> Range<usize> {
+        self.positions.range()
+    }
+}
+impl<'g, G> Iterator for GapGradient<'g, G> {
+    type Item = GapWindow<'g, G>;
+
+    fn next(&mut self) -> Option<GapWindow<'g, G>> {
+        self.positions.next()?;
+        let Range { start, end } = self.positions.range();
+        let gap = self.before.len();
+        Some(GapWindow {
+            before: &self.before[start.min(gap)..end.min(gap)],
+            after: &self.after[start.max(gap) - gap..end.max(gap) - gap],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gap_gradient_matches_closing_the_gap() {
+        let buffer = b"gap buffer";
+        for gap in 0..=buffer.len() {
+            let (before, after) = buffer.split_at(gap);
+            for max_width in [None, Some(4)] {
+                let mut expected = Gradient::with_max_width(buffer.to_vec(), max_width);
+                let mut windows = GapGradient::with_max_width(before, after, max_width);
+                assert_eq!(windows.size_hint(), expected.size_hint());
+                while let Some(window) = windows.next() {
+                    assert!(window == *expected.next().unwrap());
+                    assert_eq!(windows.range(), expected.range());
+                    let spans_gap = windows.range().start < gap && gap < windows.range().end;
+                    assert_eq!(window.as_slice().is_none(), spans_gap);
+                    assert_eq!(*window.to_cow(), buffer[windows.range()]);
+                }
+                assert_eq!(expected.next(), None);
+            }
+        }
+    }
+}
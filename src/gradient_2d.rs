@@ -0,0 +1,138 @@
+use crate::{GradientNd, WindowNd};
+use alloc::vec::Vec;
+
+/// `Rect` locates a rectangular window inside a row-major buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rect {
+    pub row: usize,
+    pub column: usize,
+    pub height: usize,
+    pub width: usize,
+}
+
+/// `Window2D` is a rectangular window yielded by [Gradient2D].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Window2D<'s, G> {
+    window: WindowNd<'s, G, 2>,
+}
+impl<'s, G> Window2D<'s, G> {
+    pub fn rect(&self) -> Rect {
+        let [row, column] = self.window.origin();
+        let [height, width] = self.window.size();
+        Rect {
+            row,
+            column,
+            height,
+            width,
+        }
+    }
+
+    /// `row` returns the `index`-th row of the window.
+    pub fn row(&self, index: usize) -> Option<&'s [G]> {
+        self.window.run(index)
+    }
+
+    /// `rows` iterates over the rows of the window, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'s [G]> + '_ {
+        self.window.runs()
+    }
+
+    pub fn to_vec(&self) -> Vec<G>
+    where
+        G: Clone,
+    {
+        self.window.to_vec()
+    }
+}
+
+/// `Gradient2D` iterates over every rectangular window of a row-major
+/// buffer `columns` elements wide, see [GradientNd].
+///
+/// Windows come in increasing height, then increasing width, and
+/// windows of the same size from top to bottom, left to right.
+///
+/// ```
+/// use gradient_slice::Gradient2D;
+/// let pixels = [1, 2, 3, 4];
+/// let windows = Gradient2D::new(&pixels, 2)
+///     .map(|window| window.to_vec())
+///     .collect::<Vec<Vec<i32>>>();
+/// assert_eq!(
+///     windows,
+///     vec![
+///         vec![1], vec![2], vec![3], vec![4],
+///         vec![1, 2], vec![3, 4],
+///         vec![1, 3], vec![2, 4],
+///         vec![1, 2, 3, 4],
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Gradient2D<'s, G> {
+    gradient: GradientNd<'s, G, 2>,
+}
+impl<'s, G> Gradient2D<'s, G> {
+    /// Panics if `columns` is zero or does not divide the length of
+    /// `input`.
+    pub fn new(input: &'s [G], columns: usize) -> Gradient2D<'s, G> {
+        assert!(columns > 0, "columns must be greater than zero");
+        assert!(
+            input.len() % columns == 0,
+            "input length must be a multiple of columns"
+        );
+        Gradient2D {
+            gradient: GradientNd::new(input, [input.len() / columns, columns]),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.gradient.shape()[0]
+    }
+
+    pub fn columns(&self) -> usize {
+        self.gradient.shape()[1]
+    }
+}
+impl<'s, G> Iterator for Gradient2D<'s, G> {
+    type Item = Window2D<'s, G>;
+
+    fn next(&mut self) -> Option<Window2D<'s, G>> {
+        self.gradient.next().map(|window| Window2D { window })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gradient_2d_counts_every_rectangle() {
+        let input = (0..12).collect::<Vec<u8>>();
+        let windows = Gradient2D::new(&input, 4).collect::<Vec<_>>();
+        // 3 * 4 / 2 row spans times 4 * 5 / 2 column spans
+        assert_eq!(windows.len(), 6 * 10);
+        let last = windows.last().unwrap();
+        assert_eq!(
+            last.rect(),
+            Rect {
+                row: 0,
+                column: 0,
+                height: 3,
+                width: 4
+            }
+        );
+        let middle = windows
+            .iter()
+            .find(|window| window.rect().height == 2 && window.rect().width == 2)
+            .unwrap();
+        assert_eq!(
+            middle.rows().collect::<Vec<_>>(),
+            vec![&[0, 1][..], &[4, 5]]
+        );
+        assert_eq!(middle.row(2), None);
+    }
+
+    #[test]
+    fn gradient_2d_empty() {
+        assert_eq!(Gradient2D::new(&[0u8; 0], 3).count(), 0);
+    }
+}
@@ -0,0 +1,104 @@
+use core::ops::Range;
+
+/// `GradientMut` visits the same windows as a [Gradient](crate::Gradient)
+/// in the same order, handing out one `&mut [G]` at a time.
+///
+/// Windows overlap, so each one borrows the `GradientMut` mutably and
+/// must be released before asking for the next, which is why this is
+/// a lending iterator rather than an [Iterator].
+///
+/// ```
+/// use gradient_slice::GradientMut;
+/// let mut coverage = vec![0; 4];
+/// let mut gradient = GradientMut::with_max_width(&mut coverage, Some(2));
+/// while let Some(window) = gradient.next_mut() {
+///     window.iter_mut().for_each(|count| *count += 1);
+/// }
+/// assert_eq!(coverage, vec![2, 3, 3, 2]);
+/// ```
+#[derive(Debug)]
+pub struct GradientMut<'s, G> {
+    input: &'s mut [G],
+    max_width: Option<usize>,
+    width: usize,
+    start: usize,
+    range: Range<usize>,
+}
+impl<'s, G> GradientMut<'s, G> {
+    pub fn new(input: &'s mut [G]) -> GradientMut<'s, G> {
+        GradientMut::with_max_width(input, None)
+    }
+
+    /// `with_max_width` creates a [GradientMut](Self) that optionally
+    /// spans to a maximum slice width.
+    pub fn with_max_width(input: &'s mut [G], max_width: Option<usize>) -> GradientMut<'s, G> {
+        GradientMut {
+            input,
+            max_width,
+            width: 1,
+            start: 0,
+            range: 0..0,
+        }
+    }
+
+    /// `next_mut` returns the next window, or `None` once every window
+    /// has been visited.
+    pub fn next_mut(&mut self) -> Option<&mut [G]> {
+        let widest = match self.max_width {
+            Some(max_width) => max_width.min(self.input.len()),
+            None => self.input.len(),
+        };
+        if self.width > widest {
+            return None;
+        }
+        self.range = self.start..self.start + self.width;
+        self.start += 1;
+        if self.start + self.width > self.input.len() {
+            self.width += 1;
+            self.start = 0;
+        }
+        Some(&mut self.input[self.range.clone()])
+    }
+
+    /// `for_each_mut` calls `f` with every remaining window.
+    pub fn for_each_mut<F: FnMut(&mut [G])>(mut self, mut f: F) {
+        while let Some(window) = self.next_mut() {
+            f(window);
+        }
+    }
+
+    /// `range` of the window most recently returned by
+    /// [next_mut](Self::next_mut).
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gradient;
+
+    #[test]
+    fn gradient_mut_matches_gradient_ranges() {
+        let mut input = " abc ".chars().collect::<Vec<char>>();
+        let mut gradient = Gradient::with_max_width(input.clone(), Some(3));
+        let mut gradient_mut = GradientMut::with_max_width(&mut input, Some(3));
+        while let Some(window) = gradient.next() {
+            assert_eq!(gradient_mut.next_mut().map(|window| &*window), Some(window));
+            assert_eq!(gradient_mut.range(), gradient.range());
+        }
+        assert_eq!(gradient_mut.next_mut(), None);
+    }
+
+    #[test]
+    fn for_each_mut_redacts_in_place() {
+        let mut input = b"secret".to_vec();
+        GradientMut::new(&mut input).for_each_mut(|window| {
+            if window.len() == 3 {
+                window[1] = b'*';
+            }
+        });
+        assert_eq!(input, b"s****t".to_vec());
+    }
+}
@@ -0,0 +1,191 @@
+use alloc::vec::Vec;
+
+/// `WindowNd` is a hyper-rectangular window yielded by [GradientNd].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WindowNd<'s, G, const D: usize> {
+    input: &'s [G],
+    shape: [usize; D],
+    origin: [usize; D],
+    size: [usize; D],
+}
+impl<'s, G, const D: usize> WindowNd<'s, G, D> {
+    /// position of the window's first element along each axis
+    pub fn origin(&self) -> [usize; D] {
+        self.origin
+    }
+
+    /// extent of the window along each axis
+    pub fn size(&self) -> [usize; D] {
+        self.size
+    }
+
+    /// `runs` iterates over the contiguous runs of the window along
+    /// its last axis, in row-major order.
+    pub fn runs(&self) -> impl Iterator<Item = &'s [G]> + '_ {
+        let count = self.size[..D.saturating_sub(1)].iter().product::<usize>();
+        (0..count).filter_map(|index| self.run(index))
+    }
+
+    /// `run` returns the `index`-th contiguous run of the window, see
+    /// [runs](Self::runs).
+    pub fn run(&self, index: usize) -> Option<&'s [G]> {
+        if D == 0 {
+            return None;
+        }
+        let mut index = index;
+        let mut offset = 0;
+        let mut stride = self.shape[D - 1];
+        offset += self.origin[D - 1];
+        for axis in (0..D - 1).rev() {
+            let position = index % self.size[axis];
+            index /= self.size[axis];
+            offset += (self.origin[axis] + position) * stride;
+            stride *= self.shape[axis];
+        }
+        if index > 0 {
+            return None;
+        }
+        Some(&self.input[offset..offset + self.size[D - 1]])
+    }
+
+    pub fn to_vec(&self) -> Vec<G>
+    where
+        G: Clone,
+    {
+        self.runs().flatten().cloned().collect()
+    }
+}
+
+/// `GradientNd` iterates over every hyper-rectangular window of a
+/// row-major buffer of the given `shape`, the last axis being
+/// contiguous.
+///
+/// Window sizes come in lexicographic order, the first axis varying
+/// slowest, and windows of the same size in row-major order of their
+/// origin.
+///
+/// ```
+/// use gradient_slice::GradientNd;
+/// let volume = (0..8).collect::<Vec<u8>>();
+/// let mut gradient = GradientNd::new(&volume, [2, 2, 2]);
+/// assert_eq!(gradient.clone().count(), 27);
+/// let cube = gradient.find(|window| window.size() == [1, 2, 2]).unwrap();
+/// assert_eq!(cube.origin(), [0, 0, 0]);
+/// assert_eq!(cube.to_vec(), vec![0, 1, 2, 3]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GradientNd<'s, G, const D: usize> {
+    input: &'s [G],
+    boxes: Boxes<D>,
+}
+impl<'s, G, const D: usize> GradientNd<'s, G, D> {
+    /// Panics if the product of `shape` differs from the length of
+    /// `input`.
+    pub fn new(input: &'s [G], shape: [usize; D]) -> GradientNd<'s, G, D> {
+        assert_eq!(
+            shape.iter().product::<usize>(),
+            input.len(),
+            "shape must cover the input exactly"
+        );
+        GradientNd {
+            input,
+            boxes: Boxes::new(shape),
+        }
+    }
+
+    pub fn shape(&self) -> [usize; D] {
+        self.boxes.shape
+    }
+}
+impl<'s, G, const D: usize> Iterator for GradientNd<'s, G, D> {
+    type Item = WindowNd<'s, G, D>;
+
+    fn next(&mut self) -> Option<WindowNd<'s, G, D>> {
+        let (origin, size) = self.boxes.next()?;
+        Some(WindowNd {
+            input: self.input,
+            shape: self.boxes.shape,
+            origin,
+            size,
+        })
+    }
+}
+
+/// `Boxes` yields the `(origin, size)` of every hyper-rectangle within
+/// `shape` in the order of [GradientNd].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Boxes<const D: usize> {
+    shape: [usize; D],
+    next: Option<([usize; D], [usize; D])>,
+}
+impl<const D: usize> Boxes<D> {
+    pub(crate) fn new(shape: [usize; D]) -> Boxes<D> {
+        let empty = D == 0 || shape.contains(&0);
+        Boxes {
+            shape,
+            next: (!empty).then_some(([0; D], [1; D])),
+        }
+    }
+
+    fn advance(&self, origin: [usize; D], size: [usize; D]) -> Option<([usize; D], [usize; D])> {
+        let mut origin = origin;
+        for axis in (0..D).rev() {
+            origin[axis] += 1;
+            if origin[axis] + size[axis] <= self.shape[axis] {
+                return Some((origin, size));
+            }
+            origin[axis] = 0;
+        }
+        let mut size = size;
+        for axis in (0..D).rev() {
+            size[axis] += 1;
+            if size[axis] <= self.shape[axis] {
+                return Some((origin, size));
+            }
+            size[axis] = 1;
+        }
+        None
+    }
+}
+impl<const D: usize> Iterator for Boxes<D> {
+    type Item = ([usize; D], [usize; D]);
+
+    fn next(&mut self) -> Option<([usize; D], [usize; D])> {
+        let (origin, size) = self.next?;
+        self.next = self.advance(origin, size);
+        Some((origin, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gradient_nd_one_axis_matches_gradient() {
+        let input = " abc ".chars().collect::<Vec<char>>();
+        let nd = GradientNd::new(&input, [5])
+            .map(|window| window.to_vec())
+            .collect::<Vec<_>>();
+        let flat = crate::Gradient::from_slice(&input)
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        assert_eq!(nd, flat);
+    }
+
+    #[test]
+    fn gradient_nd_volume_runs() {
+        let input = (0..24).collect::<Vec<u8>>();
+        let windows = GradientNd::new(&input, [2, 3, 4]).collect::<Vec<_>>();
+        assert_eq!(windows.len(), 3 * 6 * 10);
+        let block = windows
+            .iter()
+            .find(|window| window.size() == [2, 2, 2] && window.origin() == [0, 1, 2])
+            .unwrap();
+        assert_eq!(
+            block.runs().collect::<Vec<_>>(),
+            vec![&[6, 7][..], &[10, 11], &[18, 19], &[22, 23]]
+        );
+        assert_eq!(block.run(4), None);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{Gradient, Sliceable};
+use core::hash::Hash;
+use core::ops::Range;
+use std::collections::HashMap;
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `group_by_key` buckets every window by the key `f` computes
+    /// from it, mapping each key to the ranges of its windows in the
+    /// order the [Gradient](Self) yields them.
+    ///
+    /// Only ranges are stored, so grouping never clones a window;
+    /// index the input with them to get the windows back.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"aba".to_vec());
+    /// let groups = gradient.group_by_key(|window| window[0]);
+    /// assert_eq!(groups[&b'a'], vec![0..1, 2..3, 0..2, 0..3]);
+    /// assert_eq!(groups[&b'b'], vec![1..2, 1..3]);
+    /// ```
+    pub fn group_by_key<K: Hash + Eq, F: FnMut(&[G]) -> K>(
+        &self,
+        mut f: F,
+    ) -> HashMap<K, Vec<Range<usize>>> {
+        let mut groups: HashMap<K, Vec<Range<usize>>> = HashMap::new();
+        let mut gradient = self.rewound();
+        while let Some(window) = gradient.next() {
+            groups.entry(f(window)).or_default().push(gradient.range());
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_by_key_covers_every_window_once() {
+        let gradient = Gradient::with_max_width(b"abracadabra".to_vec(), Some(4));
+        let groups = gradient.group_by_key(|window| window.len() % 3);
+        let input = gradient.input.as_slice();
+        assert_eq!(
+            groups.values().map(Vec::len).sum::<usize>(),
+            gradient.rewound().count()
+        );
+        for (key, ranges) in &groups {
+            assert!(
+                ranges
+                    .iter()
+                    .all(|range| input[range.clone()].len() % 3 == *key)
+            );
+        }
+        assert_eq!(groups[&0].len(), 9);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::rolling::{Rolling, element_hashes};
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::ops::Range;
+
+/// `Hashes` yields every window of a [Gradient] along with its
+/// rolling hash, see [Gradient::hashes].
+#[derive(Clone, Debug)]
+pub struct Hashes<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    elements: Vec<u64>,
+    rolling: Option<(Range<usize>, Rolling)>,
+}
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Hashes<'a, G, S> {
+    type Item = (&'a [G], u64);
+
+    fn next(&mut self) -> Option<(&'a [G], u64)> {
+        let window = self.gradient.next()?;
+        let range = self.gradient.range();
+        let rolling = match self.rolling.take() {
+            Some((previous, mut rolling))
+                if previous.len() == range.len() && previous.start + 1 == range.start =>
+            {
+                rolling.roll(self.elements[previous.start], self.elements[range.end - 1]);
+                rolling
+            }
+            _ => Rolling::new(&self.elements[range.clone()]),
+        };
+        let hash = rolling.hash();
+        self.rolling = Some((range, rolling));
+        Some((window, hash))
+    }
+}
+
+impl<'a, G: Hash + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `hashes` pairs every window with a Rabin-Karp rolling hash of
+    /// its contents. The hash of the first window of each width takes
+    /// `O(width)` to compute, every following window of that width
+    /// takes `O(1)`.
+    ///
+    /// Hashes are deterministic and equal windows hash equally, across
+    /// positions as well as across gradients.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let hashes = Gradient::new(b"abab".to_vec())
+    ///     .hashes()
+    ///     .map(|(_, hash)| hash)
+    ///     .collect::<Vec<u64>>();
+    /// assert_eq!(hashes.len(), 10);
+    /// assert_eq!(hashes[0], hashes[2]);
+    /// assert_eq!(hashes[4], hashes[6]);
+    /// assert_ne!(hashes[4], hashes[5]);
+    /// ```
+    pub fn hashes(self) -> Hashes<'a, G, S> {
+        Hashes {
+            elements: element_hashes(self.input.as_slice()),
+            gradient: self,
+            rolling: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_match_hashing_each_window() {
+        let input = "sliding windows".chars().collect::<Vec<char>>();
+        let elements = element_hashes(&input);
+        for (window, hash) in Gradient::from_slice(&input).hashes() {
+            let start = Gradient::from_slice(&input)
+                .find_window(window)
+                .unwrap()
+                .start;
+            let expected = Rolling::new(&elements[start..start + window.len()]).hash();
+            assert_eq!(hash, expected);
+        }
+    }
+
+    #[test]
+    fn hashes_of_an_advanced_gradient() {
+        let input = b"abcdef".to_vec();
+        let mut gradient = Gradient::from_slice(&input);
+        gradient.nth(7);
+        let advanced = gradient.hashes().collect::<Vec<_>>();
+        let all = Gradient::from_slice(&input).hashes().collect::<Vec<_>>();
+        assert_eq!(advanced[..], all[8..]);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
+
+/// `WindowIndex` answers queries about the windows of an input from a
+/// suffix array and its longest-common-prefix array, built once in
+/// `O(n log² n)`, see [Gradient::index].
+///
+/// Queries concern the whole input and ignore `max_width`.
+#[derive(Clone, Debug)]
+pub struct WindowIndex<'s, G> {
+    input: &'s [G],
+    /// starts of the suffixes of the input, in lexicographic order
+    suffixes: Vec<usize>,
+    /// `lcp[i]`: length of the common prefix of `suffixes[i - 1]` and
+    /// `suffixes[i]`, `0` for `i == 0`
+    lcp: Vec<usize>,
+    /// `shared[w]`: number of adjacent suffix pairs sharing a prefix of
+    /// `w` or more elements
+    shared: Vec<usize>,
+}
+impl<'s, G: Ord> WindowIndex<'s, G> {
+    fn new(input: &'s [G]) -> WindowIndex<'s, G> {
+        let suffixes = suffix_array(input);
+        let lcp = kasai(input, &suffixes);
+        let mut shared = alloc::vec![0; input.len() + 2];
+        for length in &lcp[1.min(lcp.len())..] {
+            shared[*length] += 1;
+        }
+        for width in (0..=input.len()).rev() {
+            shared[width] += shared[width + 1];
+        }
+        WindowIndex {
+            input,
+            suffixes,
+            lcp,
+            shared,
+        }
+    }
+
+    /// range of `suffixes` starting with `window`
+    fn suffixes_starting_with(&self, window: &[G]) -> Range<usize> {
+        let prefix = |start: usize| {
+            let suffix = &self.input[start..];
+            &suffix[..window.len().min(suffix.len())]
+        };
+        let first = self
+            .suffixes
+            .partition_point(|start| prefix(*start) < window);
+        let last = self
+            .suffixes
+            .partition_point(|start| prefix(*start) <= window);
+        first..last
+    }
+
+    /// `count` of the occurrences of `window`, in `O(m log n)`
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abracadabra".to_vec());
+    /// let index = gradient.index();
+    /// assert_eq!(index.count(b"abra"), 2);
+    /// assert_eq!(index.count(b"a"), 5);
+    /// assert_eq!(index.count(b"cab"), 0);
+    /// ```
+    pub fn count(&self, window: &[G]) -> usize {
+        if window.is_empty() {
+            return 0;
+        }
+        self.suffixes_starting_with(window).len()
+    }
+
+    /// `occurrences` of `window`, as ascending starts
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abracadabra".to_vec());
+    /// assert_eq!(gradient.index().occurrences(b"abra"), vec![0, 7]);
+    /// ```
+    pub fn occurrences(&self, window: &[G]) -> Vec<usize> {
+        if window.is_empty() {
+            return Vec::new();
+        }
+        let mut starts = self.suffixes[self.suffixes_starting_with(window)].to_vec();
+        starts.sort_unstable();
+        starts
+    }
+
+    /// `distinct` returns the number of distinct windows of `width`,
+    /// in `O(1)`
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abab".to_vec());
+    /// let index = gradient.index();
+    /// assert_eq!((1..=5).map(|width| index.distinct(width)).collect::<Vec<_>>(), vec![2, 2, 2, 1, 0]);
+    /// ```
+    pub fn distinct(&self, width: usize) -> usize {
+        if width == 0 || width > self.input.len() {
+            return 0;
+        }
+        let windows = self.input.len() - width + 1;
+        windows - self.shared[width]
+    }
+
+    /// `longest_repeated` returns the widest window occurring at two
+    /// or more positions, along with their ascending starts; the
+    /// earliest such window wins ties
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"banana".to_vec());
+    /// assert_eq!(gradient.index().longest_repeated(), Some((&b"ana"[..], vec![1, 3])));
+    /// ```
+    pub fn longest_repeated(&self) -> Option<(&'s [G], Vec<usize>)> {
+        let width = self.lcp.iter().copied().max().filter(|width| *width > 0)?;
+        let start = (1..self.lcp.len())
+            .filter(|position| self.lcp[*position] == width)
+            .map(|position| self.suffixes[position - 1].min(self.suffixes[position]))
+            .min()?;
+        let window = &self.input[start..start + width];
+        Some((window, self.occurrences(window)))
+    }
+}
+
+/// suffix array by prefix doubling: suffixes sorted by their first
+/// `2^k` elements, for growing `k`, until every rank is distinct
+fn suffix_array<G: Ord>(input: &[G]) -> Vec<usize> {
+    let len = input.len();
+    let mut suffixes = (0..len).collect::<Vec<_>>();
+    suffixes.sort_by(|left, right| input[*left].cmp(&input[*right]));
+    let mut rank = alloc::vec![0; len];
+    for index in 1..len {
+        let (previous, current) = (suffixes[index - 1], suffixes[index]);
+        rank[current] = rank[previous] + usize::from(input[previous] != input[current]);
+    }
+    let mut length = 1;
+    while length < len && rank[suffixes[len - 1]] + 1 < len {
+        let key = |start: usize| (rank[start], rank.get(start + length).map(|rank| rank + 1));
+        suffixes.sort_by_key(|start| key(*start));
+        let mut next = alloc::vec![0; len];
+        for index in 1..len {
+            let (previous, current) = (suffixes[index - 1], suffixes[index]);
+            next[current] = next[previous] + usize::from(key(previous) != key(current));
+        }
+        rank = next;
+        length *= 2;
+    }
+    suffixes
+}
+
+/// Kasai's longest-common-prefix array in `O(n)`
+fn kasai<G: Ord>(input: &[G], suffixes: &[usize]) -> Vec<usize> {
+    let len = input.len();
+    let mut rank = alloc::vec![0; len];
+    for (index, start) in suffixes.iter().enumerate() {
+        rank[*start] = index;
+    }
+    let mut lcp = alloc::vec![0; len];
+    let mut common = 0;
+    for start in 0..len {
+        if rank[start] == 0 {
+            common = 0;
+            continue;
+        }
+        let previous = suffixes[rank[start] - 1];
+        while start + common < len
+            && previous + common < len
+            && input[start + common].cmp(&input[previous + common]) == Ordering::Equal
+        {
+            common += 1;
+        }
+        lcp[rank[start]] = common;
+        common = common.saturating_sub(1);
+    }
+    lcp
+}
+
+impl<'a, G: Ord + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `index` builds a [WindowIndex] over the input, answering window
+    /// queries in logarithmic or constant time rather than by
+    /// enumerating windows, at the cost of allocating a few tables as
+    /// long as the input.
+    pub fn index(&self) -> WindowIndex<'_, G> {
+        WindowIndex::new(self.input.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suffix_array_matches_sorting_suffixes() {
+        for input in ["mississippi", "aaaaaaa", "abcabcabd", "z", ""] {
+            let input = input.as_bytes();
+            let mut expected = (0..input.len()).collect::<Vec<_>>();
+            expected.sort_by_key(|start| &input[*start..]);
+            assert_eq!(suffix_array(input), expected);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn index_queries_agree_with_enumerating_windows() {
+        let input = b"gattacagattaca".to_vec();
+        let gradient = Gradient::from_slice(&input);
+        let index = gradient.index();
+        for width in 0..=input.len() + 1 {
+            assert_eq!(index.distinct(width), gradient.count_unique(Some(width)));
+        }
+        for window in gradient.clone() {
+            let starts = gradient
+                .find_all(window)
+                .map(|range| range.start)
+                .collect::<Vec<_>>();
+            assert_eq!(index.occurrences(window), starts);
+            assert_eq!(index.count(window), starts.len());
+        }
+        assert_eq!(index.longest_repeated(), gradient.longest_repeated_window());
+        assert_eq!(index.count(b""), 0);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Gradient, Sliceable};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// `Interleave` yields windows of several [Gradient]s in turn, see
+/// [Gradient::interleave].
+#[derive(Clone, Debug)]
+pub struct Interleave<'a, G, S = Vec<G>> {
+    gradients: VecDeque<(usize, Gradient<'a, G, S>)>,
+}
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Interleave<'a, G, S> {
+    type Item = (usize, &'a [G]);
+
+    fn next(&mut self) -> Option<(usize, &'a [G])> {
+        while let Some((index, mut gradient)) = self.gradients.pop_front() {
+            if let Some(window) = gradient.next() {
+                self.gradients.push_back((index, gradient));
+                return Some((index, window));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.gradients
+            .iter()
+            .map(|(_, gradient)| gradient.size_hint())
+            .fold(
+                (0, Some(0)),
+                |(low, high), (gradient_low, gradient_high)| {
+                    let high = high
+                        .zip(gradient_high)
+                        .and_then(|(high, gradient)| high.checked_add(gradient));
+                    (low.saturating_add(gradient_low), high)
+                },
+            )
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `interleave` yields one window of each of `gradients` in turn,
+    /// along with the position of the gradient it came from, so that
+    /// consumers stopping early have made progress on every input.
+    /// Gradients are skipped once they run out of windows.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let windows = Gradient::interleave([Gradient::from_slice(b"ab"), Gradient::from_slice(b"x")])
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(windows, vec![(0, &b"a"[..]), (1, b"x"), (0, b"b"), (0, b"ab")]);
+    /// ```
+    pub fn interleave(
+        gradients: impl IntoIterator<Item = Gradient<'a, G, S>>,
+    ) -> Interleave<'a, G, S> {
+        Interleave {
+            gradients: gradients.into_iter().enumerate().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleave_keeps_each_gradient_in_order() {
+        let inputs = [&b"abcd"[..], b"", b"xy", b"klmnop"];
+        let mut interleaved = Gradient::interleave(inputs.map(Gradient::from_slice));
+        let total = inputs
+            .iter()
+            .map(|input| Gradient::from_slice(input).total())
+            .sum::<usize>();
+        assert_eq!(interleaved.size_hint(), (total, Some(total)));
+        let first_round = interleaved
+            .by_ref()
+            .take(3)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        assert_eq!(first_round, vec![0, 2, 3]);
+        let mut windows = vec![Vec::new(); inputs.len()];
+        for (index, window) in Gradient::interleave(inputs.map(Gradient::from_slice)) {
+            windows[index].push(window);
+        }
+        for (input, windows) in inputs.iter().zip(windows) {
+            assert_eq!(windows, Gradient::from_slice(input).collect::<Vec<_>>());
+        }
+    }
+}
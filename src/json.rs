@@ -0,0 +1,112 @@
+use crate::{Gradient, Sliceable};
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// one line written by [Gradient::to_json_lines]
+#[derive(Serialize)]
+struct Line<'w, G> {
+    window: &'w [G],
+    start: usize,
+    end: usize,
+    width: usize,
+}
+
+impl<'a, G: Serialize + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `to_json_lines` writes every remaining window to `writer` as
+    /// one JSON object per line, along with its range and width, and
+    /// returns the number of lines written.
+    ///
+    /// `writer` isn't buffered here, wrap it in a
+    /// [BufWriter](std::io::BufWriter) when writing to a file or a
+    /// socket.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut output = Vec::new();
+    /// let lines = Gradient::with_max_width(vec![1, 2], Some(1)).to_json_lines(&mut output).unwrap();
+    /// assert_eq!(lines, 2);
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "{\"window\":[1],\"start\":0,\"end\":1,\"width\":1}\n\
+    ///      {\"window\":[2],\"start\":1,\"end\":2,\"width\":1}\n"
+    /// );
+    /// ```
+    pub fn to_json_lines<W: Write>(mut self, mut writer: W) -> io::Result<usize> {
+        let mut lines = 0;
+        while let Some(window) = self.next() {
+            let range = self.range();
+            let line = Line {
+                window,
+                start: range.start,
+                end: range.end,
+                width: window.len(),
+            };
+            serde_json::to_writer(&mut writer, &line)?;
+            writer.write_all(b"\n")?;
+            lines += 1;
+        }
+        writer.flush()?;
+        Ok(lines)
+    }
+}
+
+impl<'a, G: serde::de::DeserializeOwned> Gradient<'a, G> {
+    /// `from_json_array` reads a JSON array from `reader` and creates a
+    /// [Gradient](Self) over its values, deserialized as `G`: any
+    /// [Deserialize](serde::Deserialize) type, or
+    /// [serde_json::Value] to keep mixed values as they are.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// use serde_json::{Value, json};
+    /// let gradient = Gradient::<Value>::from_json_array(r#"[1, "two", null]"#.as_bytes()).unwrap();
+    /// assert_eq!(gradient.map(Vec::from).last(), Some(vec![json!(1), json!("two"), Value::Null]));
+    /// let typed = Gradient::<u16>::from_json_array("[3, 4]".as_bytes()).unwrap();
+    /// assert_eq!(typed.map(|window| window.iter().sum::<u16>()).collect::<Vec<_>>(), vec![3, 4, 7]);
+    /// ```
+    pub fn from_json_array<R: std::io::Read>(reader: R) -> serde_json::Result<Gradient<'a, G>> {
+        Ok(Gradient::new(serde_json::from_reader(reader)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_json_lines_round_trips() {
+        let input = "ab c".chars().collect::<Vec<_>>();
+        let mut output = Vec::new();
+        let lines = Gradient::from_slice(&input)
+            .to_json_lines(&mut output)
+            .unwrap();
+        let parsed = output
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(parsed.len(), lines);
+        let mut gradient = Gradient::from_slice(&input);
+        for line in parsed {
+            let window = gradient.next().unwrap();
+            assert_eq!(line["window"], serde_json::json!(window));
+            assert_eq!(line["start"], gradient.start());
+            assert_eq!(line["end"], gradient.end());
+            assert_eq!(line["width"], window.len());
+        }
+        assert_eq!(gradient.next(), None);
+    }
+
+    #[test]
+    fn from_json_array_rejects_other_documents() {
+        let error = Gradient::<u8>::from_json_array(r#"{"a": 1}"#.as_bytes()).unwrap_err();
+        assert!(error.is_data());
+        let error = Gradient::<u8>::from_json_array("[1, 256]".as_bytes()).unwrap_err();
+        assert!(error.is_data());
+        assert!(
+            Gradient::<u8>::from_json_array("[1,".as_bytes())
+                .unwrap_err()
+                .is_eof()
+        );
+    }
+}
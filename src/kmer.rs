@@ -0,0 +1,148 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// complement of an unambiguous base, in upper case, `None` for any
+/// other byte
+fn complement(base: u8) -> Option<u8> {
+    match base.to_ascii_uppercase() {
+        b'A' => Some(b'T'),
+        b'C' => Some(b'G'),
+        b'G' => Some(b'C'),
+        b'T' => Some(b'A'),
+        _ => None,
+    }
+}
+
+/// `Kmer` is the canonical form of a window of DNA: the window itself
+/// or its reverse complement, whichever is lexicographically smaller,
+/// without allocating the reverse complement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Kmer<'a> {
+    window: &'a [u8],
+    reverse: bool,
+}
+impl<'a> Kmer<'a> {
+    fn new(window: &'a [u8]) -> Kmer<'a> {
+        let forward = window.iter().map(u8::to_ascii_uppercase);
+        let reverse = Kmer {
+            window,
+            reverse: true,
+        };
+        Kmer {
+            window,
+            reverse: reverse.bases().cmp(forward) == Ordering::Less,
+        }
+    }
+
+    /// `window` of the input this k-mer was read from
+    pub fn window(&self) -> &'a [u8] {
+        self.window
+    }
+
+    /// `is_reverse_complement` tells whether the canonical k-mer is the
+    /// reverse complement of its [window](Self::window)
+    pub fn is_reverse_complement(&self) -> bool {
+        self.reverse
+    }
+
+    /// `bases` of the canonical k-mer, in upper case
+    pub fn bases(&self) -> impl Iterator<Item = u8> + 'a {
+        let (window, reverse) = (self.window, self.reverse);
+        (0..window.len()).map(move |index| match reverse {
+            true => complement(window[window.len() - 1 - index]).unwrap_or(b'N'),
+            false => window[index].to_ascii_uppercase(),
+        })
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bases().collect()
+    }
+}
+
+/// `Kmers` yields the canonical [Kmer] of every window of a byte
+/// [Gradient] made only of unambiguous bases, see [Gradient::kmers].
+#[derive(Clone, Debug)]
+pub struct Kmers<'a, S = Vec<u8>> {
+    gradient: Gradient<'a, u8, S>,
+    ambiguous: Vec<usize>,
+}
+impl<'a, S: Sliceable<u8>> Iterator for Kmers<'a, S> {
+    type Item = Kmer<'a>;
+
+    fn next(&mut self) -> Option<Kmer<'a>> {
+        loop {
+            let window = self.gradient.next()?;
+            let range = self.gradient.range();
+            if self.ambiguous[range.end] == self.ambiguous[range.start] {
+                return Some(Kmer::new(window));
+            }
+        }
+    }
+}
+
+impl<'a, S: Sliceable<u8>> Gradient<'a, u8, S> {
+    /// `kmers` yields the canonical [Kmer] of every window over a DNA
+    /// sequence, sweeping every `k` in gradient order, and skips the
+    /// windows containing anything but `A`, `C`, `G` and `T` in either
+    /// case, such as `N`.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let kmers = Gradient::with_max_width(b"ACGTNAA".to_vec(), Some(3))
+    ///     .kmers()
+    ///     .filter(|kmer| kmer.window().len() == 3)
+    ///     .map(|kmer| String::from_utf8(kmer.to_vec()).unwrap())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(kmers, vec!["ACG", "ACG"]);
+    /// ```
+    pub fn kmers(self) -> Kmers<'a, S> {
+        let mut ambiguous = Vec::with_capacity(self.len() + 1);
+        ambiguous.push(0);
+        for (index, base) in self.input.as_slice().iter().enumerate() {
+            ambiguous.push(ambiguous[index] + usize::from(complement(*base).is_none()));
+        }
+        Kmers {
+            gradient: self,
+            ambiguous,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reverse_complement(window: &[u8]) -> Vec<u8> {
+        window
+            .iter()
+            .rev()
+            .map(|base| complement(*base).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn kmers_are_the_smaller_strand() {
+        let input = b"GATTACAnGGCCTacgt".to_vec();
+        let kmers = Gradient::from_slice(&input).kmers().collect::<Vec<_>>();
+        let windows = Gradient::from_slice(&input)
+            .filter(|window| !window.contains(&b'n'))
+            .collect::<Vec<_>>();
+        assert_eq!(kmers.len(), windows.len());
+        for (kmer, window) in kmers.into_iter().zip(windows) {
+            assert_eq!(kmer.window(), window);
+            let forward = window.to_ascii_uppercase();
+            let canonical = forward.clone().min(reverse_complement(window));
+            assert_eq!(kmer.to_vec(), canonical);
+            assert_eq!(kmer.is_reverse_complement(), canonical != forward);
+        }
+    }
+
+    #[test]
+    fn palindromic_kmers_are_forward() {
+        let input = b"ACGT".to_vec();
+        let widest = Gradient::from_slice(&input).kmers().last().unwrap();
+        assert!(!widest.is_reverse_complement());
+        assert_eq!(Gradient::new(b"NNN".to_vec()).kmers().next(), None);
+    }
+}
@@ -1,3 +1,6 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(feature = "trusted_len", feature(trusted_len))]
 #![doc(issue_tracker_base_url = "https://github.com/gabrielfalcao/unique-pointer/issues/")]
 //! # Gradient Slice
 //!
@@ -25,10 +28,236 @@
 //!     ]
 //! );
 //! ```
+//!
+//! ## `no_std`
+//!
+//! The crate only needs [alloc] when the default `std` feature is
+//! disabled, which removes `Gradient::prefetch`. The `log`, `metrics`,
+//! `crossbeam`, `rayon`, `wasm` and `python` features turn `std` back on.
+//!
+//! ## Allocation
+//!
+//! Iterating a [Gradient] never allocates: its constructors take
+//! ownership of or borrow the input as is, and `next`, `size_hint`,
+//! `count`, `set_max_width` and `rewind` only move offsets over it.
+//! Adaptors that buffer windows or build tables, such as `prefetch`,
+//! `sorted` or `index`, say so in their documentation.
+
+extern crate alloc;
 
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::cell::Cell;
 use core::iter::Iterator;
 use core::marker::PhantomData;
 
+mod accumulate;
+pub use accumulate::{Accumulate, WindowAccumulator};
+#[cfg(feature = "std")]
+mod append;
+#[cfg(feature = "std")]
+pub use append::{AppendGradient, Appender, InputMut};
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+mod batches;
+pub use batches::{Batch, Batches};
+#[cfg(feature = "bitvec")]
+mod bits;
+#[cfg(feature = "bitvec")]
+pub use bits::BitGradient;
+mod bloom;
+pub use bloom::BloomFilter;
+mod buffered;
+pub use buffered::BufferedGradient;
+pub mod builder;
+pub use builder::GradientBuilder;
+mod chain;
+pub use chain::Chain;
+#[cfg(feature = "checksums")]
+mod checksums;
+#[cfg(feature = "checksums")]
+pub use checksums::Checksums;
+mod chunks;
+pub use chunks::{ChunkedGradient, ChunkedWindow};
+#[cfg(feature = "arrow")]
+mod columns;
+#[cfg(feature = "arrow")]
+pub use columns::ArrayGradient;
+#[cfg(feature = "std")]
+mod common;
+#[cfg(feature = "std")]
+pub use common::Common;
+mod correlate;
+pub use correlate::Correlate;
+mod de_bruijn;
+pub use de_bruijn::{DeBruijnEdge, DeBruijnEdges};
+mod dedup;
+pub use dedup::Dedup;
+mod empty;
+pub use empty::EmptyWindows;
+#[cfg(any(feature = "hex", feature = "base64"))]
+mod encoding;
+#[cfg(feature = "base64")]
+pub use encoding::Base64;
+#[cfg(feature = "hex")]
+pub use encoding::Hex;
+#[cfg(feature = "std")]
+mod entropy;
+#[cfg(feature = "std")]
+pub use entropy::Entropy;
+mod error;
+pub use error::Error;
+mod ext;
+pub use ext::{GradientExt, StrGradientExt};
+mod extremes;
+#[cfg(feature = "crossbeam")]
+mod fan_out;
+#[cfg(feature = "crossbeam")]
+pub use fan_out::{FanOut, Shutdown};
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod filter;
+pub use filter::{FilterWindows, Verdict};
+#[cfg(feature = "std")]
+mod frames;
+#[cfg(feature = "std")]
+pub use frames::{Frames, OverlapAdd, WindowFunction};
+mod fuzzy;
+pub use fuzzy::FuzzyMatching;
+mod gap;
+pub use gap::{GapGradient, GapWindow};
+mod gradient_2d;
+pub use gradient_2d::{Gradient2D, Rect, Window2D};
+mod gradient_mut;
+mod gradient_nd;
+pub use gradient_mut::GradientMut;
+pub use gradient_nd::{GradientNd, WindowNd};
+#[cfg(feature = "std")]
+mod groups;
+mod hashes;
+pub use hashes::Hashes;
+mod index;
+pub use index::WindowIndex;
+mod interleave;
+pub use interleave::Interleave;
+#[cfg(feature = "serde")]
+mod json;
+#[cfg(feature = "kmer")]
+mod kmer;
+#[cfg(feature = "kmer")]
+pub use kmer::{Kmer, Kmers};
+#[cfg(feature = "log")]
+mod logging;
+#[cfg(feature = "log")]
+pub use logging::Logged;
+#[cfg(feature = "regex")]
+mod matching;
+#[cfg(feature = "regex")]
+pub use matching::Matching;
+mod matrix;
+pub use matrix::{Equality, Hamming, SimilarityMatrix, WindowMetric};
+#[cfg(feature = "metrics")]
+mod metering;
+#[cfg(feature = "metrics")]
+pub use metering::Metered;
+#[cfg(feature = "minhash")]
+mod minhash;
+mod moments;
+pub use moments::{RunningMoments, Statistics};
+#[cfg(feature = "aho-corasick")]
+mod needles;
+#[cfg(feature = "aho-corasick")]
+pub use needles::AnyNeedle;
+#[cfg(feature = "std")]
+mod ngrams;
+mod order;
+pub use order::{Order, StartAscending, WidthAscending, WidthDescending};
+mod palindromes;
+pub use palindromes::Palindromes;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "nom")]
+mod parse;
+#[cfg(feature = "std")]
+mod prefetch;
+#[cfg(feature = "std")]
+pub use prefetch::Prefetch;
+#[cfg(feature = "memchr")]
+mod prefilter;
+#[cfg(feature = "memchr")]
+pub use prefilter::{Prefilter, Prefiltered};
+mod product;
+pub use product::Product;
+mod profiles;
+pub use profiles::{ByteProfile, CharProfile, FrequencyProfiles};
+mod progress;
+pub use progress::{OnProgress, Progress};
+mod projection;
+pub use projection::Projected;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "python")]
+pub use python::GradientPy;
+#[cfg(feature = "csv")]
+mod records;
+#[cfg(feature = "std")]
+mod repeated;
+mod rolling;
+mod runs;
+pub use runs::{RunGradient, RunWindow};
+mod samples;
+mod search;
+pub use search::FindAll;
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+pub use shared::SharedGradient;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "simd")]
+pub use simd::SimdMinMax;
+mod sliceable;
+pub use sliceable::Sliceable;
+mod sorted;
+pub use sorted::Sorted;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub use stream::ReceiverGradient;
+mod sums;
+pub use sums::{RunningSum, WhereSum};
+mod timed;
+pub use timed::Timed;
+#[cfg(feature = "image")]
+mod tiles;
+#[cfg(feature = "image")]
+pub use tiles::Tiles;
+mod top;
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(feature = "tracing")]
+pub use trace::Traced;
+#[cfg(feature = "std")]
+mod unique;
+#[cfg(feature = "std")]
+pub use unique::Unique;
+#[cfg(feature = "ndarray")]
+mod views;
+#[cfg(feature = "ndarray")]
+pub use views::ViewGradient;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "wasm")]
+pub use wasm::GradientJs;
+#[cfg(feature = "std")]
+mod without_repeats;
+#[cfg(feature = "std")]
+pub use without_repeats::WithoutRepeats;
+#[cfg(feature = "std")]
+mod write;
+mod zip;
+pub use zip::ZipWindows;
+
 /// ```
 /// use gradient_slice::Gradient;
 /// let result = Gradient::new(0x1BADB002u32.to_be_bytes().to_vec())
@@ -44,18 +273,23 @@ use core::marker::PhantomData;
 ///     ]
 /// );
 /// ```
+///
+/// The input is owned as a `Vec<G>` by default; any other [Sliceable]
+/// storage, such as a borrowed slice, can take its place. Windows are
+/// yielded in [WidthAscending] order by default; see
+/// [ordered](Gradient::ordered) for the others.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Gradient<'a, G> {
-    input: Vec<G>,
+pub struct Gradient<'a, G, S = Vec<G>, O = WidthAscending> {
+    input: S,
     start: usize,
     end: usize,
     width: usize,
     wide: bool,
     max_width: Option<usize>,
 
-    _marker: PhantomData<&'a G>,
+    _marker: PhantomData<(&'a G, O)>,
 }
-impl<'a, G: 'a> Iterator for Gradient<'a, G> {
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Gradient<'a, G, S> {
     type Item = &'a [G];
 
     fn next(&mut self) -> Option<&'a [G]> {
@@ -81,9 +315,31 @@ impl<'a, G: 'a> Iterator for Gradient<'a, G> {
         }
         Some(self.window())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.try_count() {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// When more than `usize::MAX` windows are left, which
+    /// [try_count](Gradient::try_count) reports as an error instead.
+    fn count(self) -> usize {
+        self.try_count()
+            .expect("more windows left than fit in a usize")
+    }
 }
+// SAFETY: `size_hint` is the exact number of windows left, `total`
+// minus `yielded`, which stays at zero once the gradient is finished,
+// or has no upper bound when that number overflows a `usize`
+#[cfg(feature = "trusted_len")]
+unsafe impl<'a, G: 'a, S: Sliceable<G>> core::iter::TrustedLen for Gradient<'a, G, S> {}
 impl<'a, G: Clone + 'a> Gradient<'a, G> {
-    pub fn input(&self) -> Vec<G> {
+    /// `input_cloned` returns a copy of the whole input.
+    pub fn input_cloned(&self) -> Vec<G> {
         self.input.clone()
     }
 
@@ -115,21 +371,15 @@ impl<'a, G: Clone + 'a> Gradient<'a, G> {
         }
     }
 }
-impl<'a, G: 'a> Gradient<'a, G> {
+impl<'a, G: 'a, S: Sliceable<G>, O> Gradient<'a, G, S, O> {
     pub fn window(&self) -> &'a [G] {
-        unsafe { core::mem::transmute::<&[G], &'a [G]>(&self.input[self.range()]) }
+        self.slice(self.range())
     }
 
-    pub fn finished(&self) -> bool {
-        if self.len() == 0 {
-            return true;
-        }
-        if self.end == self.len() {
-            if self.width == self.len() {
-                return true;
-            }
-        }
-        false
+    /// elements of the input within `range`, borrowed for `'a` like
+    /// every window
+    pub(crate) fn slice(&self, range: core::ops::Range<usize>) -> &'a [G] {
+        unsafe { core::mem::transmute::<&[G], &'a [G]>(&self.input.as_slice()[range]) }
     }
 
     pub fn width(&self) -> usize {
@@ -148,13 +398,162 @@ impl<'a, G: 'a> Gradient<'a, G> {
         self.start()..self.end()
     }
 
+    /// `input` borrows the whole input the [Gradient](Self) slides
+    /// over, without requiring its elements to be `Clone`.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// struct Reading(u8);
+    /// let gradient = Gradient::new(vec![Reading(1), Reading(2)]);
+    /// assert_eq!(gradient.input().iter().map(|reading| reading.0).sum::<u8>(), 3);
+    /// ```
+    pub fn input(&self) -> &[G] {
+        self.input.as_slice()
+    }
+
     pub fn len(&self) -> usize {
-        self.input.len()
+        self.input.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.input.as_slice().is_empty()
+    }
+
+    pub fn max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    /// `set_max_width` changes the widest window the [Gradient](Self)
+    /// yields in place, taking effect from its next window; it returns
+    /// the gradient to chain further setters.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut gradient = Gradient::new(vec![1, 2, 3]);
+    /// assert_eq!(gradient.set_max_width(Some(1)).count(), 3);
+    /// ```
+    pub fn set_max_width(&mut self, max_width: Option<usize>) -> &mut Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// `rewind` moves the [Gradient](Self) back before its first
+    /// window, so it can make another pass over the same input without
+    /// being rebuilt.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut gradient = Gradient::new(vec![1, 2, 3]);
+    /// assert_eq!(gradient.by_ref().count(), 6);
+    /// assert_eq!(gradient.rewind().set_max_width(Some(2)).count(), 5);
+    /// ```
+    pub fn rewind(&mut self) -> &mut Self {
+        (self.start, self.end, self.width, self.wide) = (0, 0, 1, true);
+        self
+    }
+
+    /// `total` returns the number of windows the [Gradient](Self)
+    /// yields from start to finish, taking `max_width` into account.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// assert_eq!(Gradient::new(vec![1, 2, 3, 4]).total(), 10);
+    /// assert_eq!(Gradient::with_max_width(vec![1, 2, 3, 4], Some(2)).total(), 7);
+    /// ```
+    ///
+    /// It saturates at `usize::MAX`, which [try_total](Self::try_total)
+    /// reports as an error instead.
+    pub fn total(&self) -> usize {
+        windows_narrower_than(self.len(), self.widest() + 1)
+    }
+
+    /// `try_total` is [total](Self::total) failing with
+    /// [Error::CountOverflow] when there are more windows than fit in a
+    /// `usize`, as on 32-bit targets with inputs of ~100K elements.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// assert_eq!(Gradient::new(vec![1, 2, 3, 4]).try_total(), Ok(10));
+    /// ```
+    pub fn try_total(&self) -> Result<usize, Error> {
+        usize::try_from(self.total_u128()).map_err(|_| Error::CountOverflow)
+    }
+
+    pub(crate) fn total_u128(&self) -> u128 {
+        windows_narrower_than_u128(self.len(), self.widest() + 1)
+    }
+
+    /// width of the widest window the [Gradient](Self) yields
+    pub(crate) fn widest(&self) -> usize {
+        match self.max_width {
+            Some(max_width) => max_width.min(self.len()),
+            None => self.len(),
+        }
+    }
+}
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    pub fn finished(&self) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        if self.end == self.len() && self.width == self.len() {
+            return true;
+        }
+        false
+    }
+
+    /// `try_count` returns the number of windows left to yield, without
+    /// consuming the [Gradient](Self), failing with
+    /// [Error::CountOverflow] when they do not fit in a `usize`.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut gradient = Gradient::new(vec![1, 2, 3]);
+    /// gradient.next();
+    /// assert_eq!(gradient.try_count(), Ok(5));
+    /// ```
+    pub fn try_count(&self) -> Result<usize, Error> {
+        usize::try_from(self.total_u128() - self.yielded_u128()).map_err(|_| Error::CountOverflow)
     }
 
-    pub fn new(s: Vec<G>) -> Gradient<'a, G> {
+    /// a [Gradient] borrowing the same input with the same
+    /// `max_width`, starting over from the first window
+    pub(crate) fn rewound(&self) -> Gradient<'_, G, &[G]> {
         Gradient {
-            input: s,
+            max_width: self.max_width,
+            ..Gradient::from_slice(self.input.as_slice())
+        }
+    }
+
+    /// width of the next window the [Gradient](Self) yields, `None`
+    /// once it is finished
+    pub(crate) fn next_width(&self) -> Option<usize> {
+        let width = if self.wide {
+            self.width
+        } else {
+            self.width + 1
+        };
+        (width <= self.widest()).then_some(width)
+    }
+
+    /// number of windows already yielded, out of [total](Self::total)
+    pub(crate) fn yielded(&self) -> usize {
+        usize::try_from(self.yielded_u128()).unwrap_or(usize::MAX)
+    }
+
+    fn yielded_u128(&self) -> u128 {
+        if self.end == 0 {
+            return 0;
+        }
+        let before = windows_narrower_than_u128(self.len(), self.width);
+        (before + (self.end + 1 - self.width) as u128).min(self.total_u128())
+    }
+
+    /// `from_sliceable` creates a [Gradient](Self) over any
+    /// [Sliceable] storage.
+    pub const fn from_sliceable(input: S) -> Gradient<'a, G, S> {
+        Gradient {
+            input,
             start: 0,
             end: 0,
             width: 1,
@@ -164,10 +563,172 @@ impl<'a, G: 'a> Gradient<'a, G> {
         }
     }
 }
+impl<'a, G: 'a> Gradient<'a, G> {
+    pub const fn new(s: Vec<G>) -> Gradient<'a, G> {
+        Gradient::from_sliceable(s)
+    }
+}
+impl<'a, G: 'a> Gradient<'a, G, &'a [G]> {
+    /// `from_slice` creates a [Gradient](Self) that borrows its input
+    /// instead of owning it.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let input = [1, 2, 3];
+    /// let widths = Gradient::from_slice(&input).map(<[i32]>::len).collect::<Vec<usize>>();
+    /// assert_eq!(widths, vec![1, 1, 1, 2, 2, 3]);
+    /// ```
+    ///
+    /// Like the other borrowing constructors it is a `const fn`, so a
+    /// gradient over a static table can be built at compile time.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// static TABLE: [u8; 3] = [1, 2, 3];
+    /// const GRADIENT: Gradient<u8, &[u8]> = Gradient::from_slice(&TABLE);
+    /// assert_eq!(GRADIENT.last(), Some(&TABLE[..]));
+    /// ```
+    pub const fn from_slice(s: &'a [G]) -> Gradient<'a, G, &'a [G]> {
+        Gradient::from_sliceable(s)
+    }
+
+    /// `from_array` creates a [Gradient](Self) over a fixed-size array
+    /// without allocating, e.g. on `no_std` targets.
+    ///
+    /// The array is borrowed rather than moved in: windows yielded by
+    /// an owned inline array would point into the gradient itself and
+    /// dangle as soon as it moved.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// static READINGS: [u16; 3] = [7, 8, 9];
+    /// let sums = Gradient::from_array(&READINGS)
+    ///     .map(|window| window.iter().sum::<u16>())
+    ///     .collect::<Vec<u16>>();
+    /// assert_eq!(sums, vec![7, 8, 9, 15, 17, 24]);
+    /// ```
+    pub const fn from_array<const N: usize>(s: &'a [G; N]) -> Gradient<'a, G, &'a [G]> {
+        Gradient::from_sliceable(s)
+    }
+
+    /// `from_deque` creates a [Gradient](Self) over a ring buffer,
+    /// rearranging its elements in place with
+    /// [VecDeque::make_contiguous] rather than copying them.
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use gradient_slice::Gradient;
+    /// let mut ring = VecDeque::from(vec![2, 3]);
+    /// ring.push_front(1);
+    /// let widths = Gradient::from_deque(&mut ring).map(Vec::from).collect::<Vec<_>>();
+    /// assert_eq!(widths, vec![vec![1], vec![2], vec![3], vec![1, 2], vec![2, 3], vec![1, 2, 3]]);
+    /// ```
+    pub fn from_deque(s: &'a mut VecDeque<G>) -> Gradient<'a, G, &'a [G]> {
+        Gradient::from_sliceable(s.make_contiguous())
+    }
+}
+/// Borrows any contiguous container, such as `SmallVec`, `ArrayVec`
+/// or `TinyVec`, without converting it to a `Vec` first.
+///
+/// ```
+/// use gradient_slice::Gradient;
+/// let input = String::from("ab");
+/// let gradient: Gradient<u8, &[u8]> = Gradient::from(&input);
+/// assert_eq!(gradient.collect::<Vec<_>>(), vec![&b"a"[..], b"b", b"ab"]);
+/// ```
+impl<'a, G: 'a, T: AsRef<[G]> + ?Sized> From<&'a T> for Gradient<'a, G, &'a [G]> {
+    fn from(s: &'a T) -> Gradient<'a, G, &'a [G]> {
+        Gradient::from_sliceable(s.as_ref())
+    }
+}
+impl<'a, G: 'a> Gradient<'a, Cell<G>, &'a [Cell<G>]> {
+    /// `from_cells` creates a [Gradient](Self) over the elements of
+    /// `s` as [Cell]s, so that every window can be written through
+    /// while other windows aliasing the same elements are alive.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut input = [1, 2, 3];
+    /// let windows = Gradient::from_cells(&mut input).collect::<Vec<_>>();
+    /// for window in &windows {
+    ///     window.iter().for_each(|cell| cell.set(cell.get() * 2));
+    /// }
+    /// assert_eq!(windows[0][0].get(), 8);
+    /// assert_eq!(input, [8, 32, 24]);
+    /// ```
+    pub fn from_cells(s: &'a mut [G]) -> Gradient<'a, Cell<G>, &'a [Cell<G>]> {
+        Gradient::from_sliceable(Cell::from_mut(s).as_slice_of_cells())
+    }
+}
+
+/// number of windows narrower than `width` over an input of `len`
+/// elements, which is also the index of the first window of `width`,
+/// saturating at `usize::MAX`
+pub(crate) fn windows_narrower_than(len: usize, width: usize) -> usize {
+    usize::try_from(windows_narrower_than_u128(len, width)).unwrap_or(usize::MAX)
+}
+
+/// [windows_narrower_than] counted in `u128`, which the square of any
+/// `usize` length fits in
+pub(crate) fn windows_narrower_than_u128(len: usize, width: usize) -> u128 {
+    let (len, narrower) = (len as u128, width.saturating_sub(1) as u128);
+    narrower * (len + 1) - narrower * (narrower + 1) / 2
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    /// counts the allocations made by each thread, so that tests
+    /// running concurrently do not see each other's
+    struct CountingAllocator;
+
+    std::thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let before = ALLOCATIONS.with(Cell::get);
+        let result = f();
+        (result, ALLOCATIONS.with(Cell::get) - before)
+    }
+
+    #[test]
+    fn iterating_never_allocates() {
+        let owned = (0..64u32).collect::<Vec<u32>>();
+        let (sum, count) = allocations(|| {
+            let mut gradient = Gradient::with_max_width(owned, Some(8));
+            let mut sum = gradient.by_ref().map(|window| window.len()).sum::<usize>();
+            gradient.rewind().set_max_width(None);
+            sum += gradient.size_hint().0 + gradient.try_count().unwrap();
+            sum += gradient.by_ref().take(100).count() + gradient.count();
+            sum
+        });
+        assert_eq!((sum, count), (2136 + 3 * 2080, 0));
+        let input = [1u8, 2, 3];
+        let (windows, count) = allocations(|| {
+            Gradient::from_array(&input).count()
+                + Gradient::try_with_max_width(Vec::<u8>::new(), Some(1))
+                    .unwrap()
+                    .count()
+        });
+        assert_eq!((windows, count), (6, 0));
+    }
 
     #[test]
     fn gradient() {
@@ -196,6 +757,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn size_hint_is_exact() {
+        for (len, max_width) in [(0, None), (1, None), (5, None), (5, Some(2)), (6, Some(9))] {
+            let mut gradient = Gradient::with_max_width((0..len).collect(), max_width);
+            let mut remaining = gradient.total();
+            loop {
+                assert_eq!(gradient.size_hint(), (remaining, Some(remaining)));
+                if gradient.next().is_none() {
+                    break;
+                }
+                remaining -= 1;
+            }
+            assert_eq!((remaining, gradient.len()), (0, len));
+            assert_eq!(gradient.next(), None);
+            assert_eq!(gradient.size_hint(), (0, Some(0)));
+        }
+    }
+
     #[test]
     fn max_width() {
         let result = Gradient::with_max_width(" abc ".chars().collect(), Some(2))
@@ -212,4 +791,141 @@ mod tests {
             vec![" ", "a", "b", "c", " ", " a", "ab", "bc", "c "]
         );
     }
+
+    #[test]
+    fn cells_are_written_while_iterating() {
+        let mut input = *b"abc";
+        let mut gradient = Gradient::from_cells(&mut input);
+        let first = gradient.next().unwrap();
+        for window in gradient {
+            window[0].set(window[0].get().to_ascii_uppercase());
+        }
+        assert_eq!(first[0].get(), b'A');
+        assert_eq!(&input, b"ABC");
+    }
+
+    #[test]
+    fn from_slice_borrows_input() {
+        let input = " abc ".chars().collect::<Vec<char>>();
+        assert!(Gradient::from_slice(&input).eq(Gradient::new(input.clone())));
+    }
+
+    #[test]
+    fn setters_reconfigure_between_passes() {
+        struct Holder {
+            gradient: Gradient<'static, u8, &'static [u8]>,
+        }
+        let mut holder = Holder {
+            gradient: Gradient::from_slice(b"abcd"),
+        };
+        let first = holder.gradient.by_ref().take(5).collect::<Vec<_>>();
+        assert_eq!(first.last(), Some(&&b"ab"[..]));
+        holder.gradient.set_max_width(Some(2));
+        assert_eq!(holder.gradient.size_hint(), (2, Some(2)));
+        assert_eq!(holder.gradient.by_ref().count(), 2);
+        holder.gradient.rewind().set_max_width(None);
+        assert_eq!(holder.gradient.max_width(), None);
+        assert_eq!(
+            holder.gradient.collect::<Vec<_>>(),
+            Gradient::from_slice(b"abcd").collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn counting_more_windows_than_fit_in_a_usize() {
+        let len = usize::MAX / 2;
+        // SAFETY: a slice of zero-sized elements needs no allocation
+        let input = unsafe {
+            core::slice::from_raw_parts(core::ptr::NonNull::<()>::dangling().as_ptr(), len)
+        };
+        let mut gradient = Gradient::from_slice(input);
+        assert_eq!(
+            (gradient.total(), gradient.try_total()),
+            (usize::MAX, Err(Error::CountOverflow))
+        );
+        assert_eq!(gradient.next().map(<[()]>::len), Some(1));
+        assert_eq!(gradient.size_hint(), (usize::MAX, None));
+        gradient.set_max_width(Some(2));
+        assert_eq!(gradient.try_total(), Ok(2 * len - 1));
+        assert_eq!(gradient.try_count(), Ok(2 * len - 2));
+    }
+
+    #[test]
+    fn const_constructors() {
+        const TABLE: [char; 2] = ['a', 'b'];
+        const FROM_ARRAY: Gradient<char, &[char]> = Gradient::from_array(&TABLE);
+        const FROM_SLICEABLE: Gradient<char, &[char]> = Gradient::from_sliceable(&TABLE);
+        assert_eq!(FROM_ARRAY, Gradient::from_slice(&TABLE));
+        assert_eq!(
+            FROM_SLICEABLE.collect::<Vec<_>>(),
+            vec![&['a'][..], &['b'], &['a', 'b']]
+        );
+    }
+
+    #[test]
+    fn from_deque_after_wrapping() {
+        let mut ring = VecDeque::with_capacity(4);
+        ring.extend(" ab".chars());
+        ring.pop_front();
+        ring.extend("c ".chars());
+        ring.push_front(' ');
+        let capacity = ring.capacity();
+        let expected = " abc ".chars().collect::<Vec<char>>();
+        assert!(Gradient::from_deque(&mut ring).eq(Gradient::from_slice(&expected)));
+        assert_eq!(ring.capacity(), capacity);
+        ring.pop_front();
+        assert_eq!(Gradient::from_deque(&mut ring).count(), 10);
+    }
+
+    #[test]
+    fn from_array_without_heap() {
+        let input = [1u8, 2, 3, 4];
+        let mut gradient = Gradient::from_array(&input);
+        let first = gradient.next();
+        let last = gradient.last();
+        assert_eq!(first, Some(&input[..1]));
+        assert_eq!(last, Some(&input[..]));
+    }
+
+    #[test]
+    fn from_small_buffer_containers() {
+        let expected = Gradient::new(vec![1, 2, 3])
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        let small = smallvec::SmallVec::<[i32; 4]>::from_slice(&[1, 2, 3]);
+        let array = arrayvec::ArrayVec::<i32, 4>::try_from(&[1, 2, 3][..]).unwrap();
+        let tiny = tinyvec::tiny_vec!([i32; 4] => 1, 2, 3);
+        assert_eq!(
+            Gradient::from(&small).map(Vec::from).collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(
+            Gradient::from(&array).map(Vec::from).collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(
+            Gradient::from(&tiny).map(Vec::from).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn from_sliceable_custom_storage() {
+        struct Rope {
+            chunk: Vec<char>,
+        }
+        unsafe impl Sliceable<char> for Rope {
+            fn as_slice(&self) -> &[char] {
+                &self.chunk
+            }
+        }
+        let rope = Rope {
+            chunk: " abc ".chars().collect(),
+        };
+        let mut gradient = Gradient::from_sliceable(rope);
+        let first = gradient.next().unwrap();
+        let mut moved = gradient;
+        assert_eq!(moved.by_ref().count(), 14);
+        assert_eq!(first, &[' ']);
+    }
 }
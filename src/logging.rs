@@ -0,0 +1,118 @@
+use crate::{Gradient, Sliceable};
+use std::time::Instant;
+
+/// `Logged` is an iterator adaptor that logs each finished width
+/// pass at debug level, see [Gradient::logged].
+pub struct Logged<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    pass: Option<Pass>,
+}
+
+struct Pass {
+    width: usize,
+    windows: usize,
+    started: Instant,
+}
+impl Pass {
+    fn new(width: usize) -> Pass {
+        log::debug!("gradient width {} started", width);
+        Pass {
+            width,
+            windows: 0,
+            started: Instant::now(),
+        }
+    }
+
+    fn finish(self) {
+        log::debug!(
+            "gradient width {} finished: {} windows in {:?}",
+            self.width,
+            self.windows,
+            self.started.elapsed()
+        );
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Logged<'a, G, S> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        let Some(window) = self.gradient.next() else {
+            if let Some(pass) = self.pass.take() {
+                pass.finish();
+            }
+            return None;
+        };
+        let width = window.len();
+        if let Some(pass) = self.pass.take_if(|pass| pass.width != width) {
+            pass.finish();
+        }
+        self.pass.get_or_insert_with(|| Pass::new(width)).windows += 1;
+        Some(window)
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `logged` emits [log] debug messages whenever a width pass
+    /// starts and finishes, the latter carrying the number of windows
+    /// in the pass and the time it took to consume them.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let count = Gradient::new(vec![1, 2, 3]).logged().count();
+    /// assert_eq!(count, 6);
+    /// ```
+    pub fn logged(self) -> Logged<'a, G, S> {
+        Logged {
+            gradient: self,
+            pass: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct Capture;
+    impl log::Log for Capture {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            let message = record.args().to_string();
+            let message = match message.split_once(" in ") {
+                Some((message, _elapsed)) => message.to_string(),
+                None => message,
+            };
+            MESSAGES.lock().unwrap().push(message);
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn logged_reports_every_width_pass() {
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(log::LevelFilter::Debug);
+        let mut logged = Gradient::new(vec![1, 2, 3]).logged();
+        assert_eq!(logged.by_ref().count(), 6);
+        assert_eq!(
+            *MESSAGES.lock().unwrap(),
+            vec![
+                "gradient width 1 started",
+                "gradient width 1 finished: 3 windows",
+                "gradient width 2 started",
+                "gradient width 2 finished: 2 windows",
+                "gradient width 3 started",
+                "gradient width 3 finished: 1 windows",
+            ]
+        );
+        assert_eq!(logged.next(), None);
+        assert_eq!(MESSAGES.lock().unwrap().len(), 6);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{Gradient, Sliceable};
+use core::ops::Range;
+use regex_automata::dfa::Automaton;
+use regex_automata::dfa::dense::{self, BuildError, DFA};
+use regex_automata::{Anchored, Input, MatchKind, dfa::StartKind};
+use std::vec;
+
+/// `Matching` yields the windows of a [Gradient] whose full contents
+/// match a regular expression, in gradient order, see
+/// [Gradient::matching].
+#[derive(Clone, Debug)]
+pub struct Matching<'s, G> {
+    input: &'s [G],
+    ranges: vec::IntoIter<Range<usize>>,
+}
+impl<'s, G> Iterator for Matching<'s, G> {
+    type Item = &'s [G];
+
+    fn next(&mut self) -> Option<&'s [G]> {
+        let range = self.ranges.next()?;
+        Some(&self.input[range])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ranges.size_hint()
+    }
+}
+impl<G> ExactSizeIterator for Matching<'_, G> {}
+
+fn compile(pattern: &str) -> Result<DFA<Vec<u32>>, Box<BuildError>> {
+    dense::Builder::new()
+        .configure(
+            dense::Config::new()
+                .start_kind(StartKind::Anchored)
+                .match_kind(MatchKind::All),
+        )
+        .build(pattern)
+        .map_err(Box::new)
+}
+
+/// ranges, in elements, of every window of `widest` elements or fewer
+/// whose bytes fully match `dfa`, where element `i` is encoded as
+/// `bytes[offsets[i]..offsets[i + 1]]`
+fn full_matches(
+    dfa: &DFA<Vec<u32>>,
+    bytes: &[u8],
+    offsets: &[usize],
+    widest: usize,
+) -> Vec<Range<usize>> {
+    let elements = offsets.len() - 1;
+    let mut ranges = Vec::new();
+    for start in 0..elements {
+        let input = Input::new(&bytes[offsets[start]..]).anchored(Anchored::Yes);
+        let Ok(mut state) = dfa.start_state_forward(&input) else {
+            continue;
+        };
+        for end in start + 1..=elements.min(start + widest) {
+            for byte in &bytes[offsets[end - 1]..offsets[end]] {
+                state = dfa.next_state(state, *byte);
+            }
+            if dfa.is_dead_state(state) || dfa.is_quit_state(state) {
+                break;
+            }
+            if dfa.is_match_state(dfa.next_eoi_state(state)) {
+                ranges.push(start..end);
+            }
+        }
+    }
+    ranges.sort_by_key(|range| (range.len(), range.start));
+    ranges
+}
+
+impl<'a, S: Sliceable<u8>> Gradient<'a, u8, S> {
+    /// `matching` compiles `pattern` to a DFA and yields only the
+    /// windows whose full contents match it, in gradient order, or
+    /// returns why `pattern` failed to compile.
+    ///
+    /// From every start, the DFA consumes one element per width and
+    /// stops at the first width from which no wider window can match,
+    /// so windows are never materialized.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let log = Gradient::new(b"id=42;id=7".to_vec());
+    /// let ids = log.matching(r"id=\d+").unwrap().collect::<Vec<&[u8]>>();
+    /// assert_eq!(ids, vec![&b"id=4"[..], b"id=7", b"id=42"]);
+    /// ```
+    pub fn matching(&self, pattern: &str) -> Result<Matching<'_, u8>, Box<BuildError>> {
+        let input = self.input.as_slice();
+        let offsets = (0..=input.len()).collect::<Vec<_>>();
+        let ranges = full_matches(&compile(pattern)?, input, &offsets, self.widest());
+        Ok(Matching {
+            input,
+            ranges: ranges.into_iter(),
+        })
+    }
+}
+
+impl<'a, S: Sliceable<char>> Gradient<'a, char, S> {
+    /// `matching` compiles `pattern` to a DFA and yields only the
+    /// windows of chars whose full contents match it, in gradient
+    /// order, like [`Gradient<u8>::matching`](Gradient::matching).
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let text = Gradient::new("héllo wörld".chars().collect());
+    /// let words = text
+    ///     .matching(r"\w{5}")
+    ///     .unwrap()
+    ///     .map(|window| window.iter().collect::<String>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(words, vec!["héllo", "wörld"]);
+    /// ```
+    pub fn matching(&self, pattern: &str) -> Result<Matching<'_, char>, Box<BuildError>> {
+        let input = self.input.as_slice();
+        let text = input.iter().collect::<String>();
+        let mut offsets = Vec::with_capacity(input.len() + 1);
+        offsets.extend(text.char_indices().map(|(offset, _)| offset));
+        offsets.push(text.len());
+        let ranges = full_matches(&compile(pattern)?, text.as_bytes(), &offsets, self.widest());
+        Ok(Matching {
+            input,
+            ranges: ranges.into_iter(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_agrees_with_matching_each_window() {
+        let input = b"aab ab a_b 0x1F ba".to_vec();
+        for pattern in [r"a+b", r"[0-9a-fA-Fx]+", r"\s", r"a.?b", r"^b"] {
+            let whole = regex::bytes::Regex::new(&format!("^(?:{})$", pattern)).unwrap();
+            let expected = Gradient::from_slice(&input)
+                .filter(|window| whole.is_match(window))
+                .collect::<Vec<_>>();
+            let gradient = Gradient::from_slice(&input);
+            let matching = gradient.matching(pattern).unwrap().collect::<Vec<_>>();
+            assert_eq!(matching, expected, "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn matching_respects_max_width() {
+        let gradient = Gradient::with_max_width("aaaa".chars().collect(), Some(2));
+        assert_eq!(gradient.matching("a+").unwrap().len(), 4 + 3);
+        assert!(gradient.matching("(").is_err());
+    }
+}
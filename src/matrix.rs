@@ -0,0 +1,195 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+
+/// `WindowMetric` scores how similar two windows of the same width
+/// are, see [Gradient::similarity_matrix].
+///
+/// Metrics are expected to be symmetric, since [SimilarityMatrix]
+/// only stores one score per pair. Any `Fn(&[G], &[G]) -> f64` is a
+/// metric.
+pub trait WindowMetric<G> {
+    /// `similarity` of `left` and `right`
+    fn similarity(&self, left: &[G], right: &[G]) -> f64;
+}
+impl<G, F: Fn(&[G], &[G]) -> f64> WindowMetric<G> for F {
+    fn similarity(&self, left: &[G], right: &[G]) -> f64 {
+        self(left, right)
+    }
+}
+
+/// `Hamming` scores the fraction of positions at which two windows
+/// hold equal elements, from `0.0` to `1.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hamming;
+impl<G: PartialEq> WindowMetric<G> for Hamming {
+    fn similarity(&self, left: &[G], right: &[G]) -> f64 {
+        if left.is_empty() {
+            return 1.0;
+        }
+        let equal = left.iter().zip(right).filter(|(a, b)| a == b).count();
+        equal as f64 / left.len() as f64
+    }
+}
+
+/// `Equality` scores `1.0` for windows with equal contents and `0.0`
+/// otherwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Equality;
+impl<G: PartialEq> WindowMetric<G> for Equality {
+    fn similarity(&self, left: &[G], right: &[G]) -> f64 {
+        if left == right { 1.0 } else { 0.0 }
+    }
+}
+
+/// `SimilarityMatrix` holds the similarity of every pair of windows of
+/// one width, indexed by window start, see [Gradient::similarity_matrix].
+///
+/// Only the upper triangle and the diagonal are stored, `len * (len +
+/// 1) / 2` scores in row-major order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimilarityMatrix {
+    len: usize,
+    scores: Vec<f64>,
+}
+impl SimilarityMatrix {
+    /// offset of row `row` in `scores`, `row <= column`
+    fn offset(&self, row: usize) -> usize {
+        row * self.len - row * row.saturating_sub(1) / 2
+    }
+
+    /// `len` is the number of windows, the number of rows and of
+    /// columns
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `is_empty` when there was no window of the requested width
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `get` the similarity of the windows starting at `row` and at
+    /// `column`, `None` when either is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<f64> {
+        let (row, column) = (row.min(column), row.max(column));
+        if column >= self.len {
+            return None;
+        }
+        Some(self.scores[self.offset(row) + column - row])
+    }
+
+    /// `row` of similarities between the window starting at `row` and
+    /// every window, by start
+    pub fn row(&self, row: usize) -> impl Iterator<Item = f64> + '_ {
+        (0..self.len).filter_map(move |column| self.get(row, column))
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `similarity_matrix` scores every pair of windows of `width`
+    /// with `metric`, e.g. for self-similarity dot plots. It is empty
+    /// when `width` is zero or wider than the [Gradient](Self) goes.
+    ///
+    /// ```
+    /// use gradient_slice::{Gradient, Hamming};
+    /// let gradient = Gradient::new(b"abab".to_vec());
+    /// let matrix = gradient.similarity_matrix(2, Hamming);
+    /// assert_eq!(matrix.len(), 3);
+    /// assert_eq!(matrix.row(0).collect::<Vec<_>>(), vec![1.0, 0.0, 1.0]);
+    /// assert_eq!(matrix.get(2, 1), Some(0.0));
+    /// ```
+    pub fn similarity_matrix<M: WindowMetric<G>>(
+        &self,
+        width: usize,
+        metric: M,
+    ) -> SimilarityMatrix {
+        let windows = self.windows_of_width(width);
+        let mut scores = Vec::with_capacity(windows.len() * (windows.len() + 1) / 2);
+        for (row, left) in windows.iter().enumerate() {
+            scores.extend(
+                windows[row..]
+                    .iter()
+                    .map(|right| metric.similarity(left, right)),
+            );
+        }
+        SimilarityMatrix {
+            len: windows.len(),
+            scores,
+        }
+    }
+
+    /// every window of `width`, none past the widest
+    fn windows_of_width(&self, width: usize) -> Vec<&[G]> {
+        if width == 0 || width > self.widest() {
+            return Vec::new();
+        }
+        self.input.as_slice().windows(width).collect()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, G: Sync + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `par_similarity_matrix` is
+    /// [similarity_matrix](Self::similarity_matrix) scoring each row as
+    /// an independent [rayon] job.
+    ///
+    /// ```
+    /// use gradient_slice::{Equality, Gradient};
+    /// let gradient = Gradient::new(b"abab".to_vec());
+    /// assert_eq!(
+    ///     gradient.par_similarity_matrix(2, Equality),
+    ///     gradient.similarity_matrix(2, Equality)
+    /// );
+    /// ```
+    pub fn par_similarity_matrix<M: WindowMetric<G> + Sync>(
+        &self,
+        width: usize,
+        metric: M,
+    ) -> SimilarityMatrix {
+        use rayon::prelude::*;
+        let windows = self.windows_of_width(width);
+        let rows = (0..windows.len())
+            .into_par_iter()
+            .map(|row| {
+                windows[row..]
+                    .iter()
+                    .map(|column| metric.similarity(windows[row], column))
+                    .collect::<Vec<f64>>()
+            })
+            .collect::<Vec<_>>();
+        SimilarityMatrix {
+            len: windows.len(),
+            scores: rows.concat(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn similarity_matrix_scores_every_pair() {
+        let input = b"gattacagat".to_vec();
+        let gradient = Gradient::from_slice(&input);
+        let metric = |left: &[u8], right: &[u8]| (left[0] as f64) * 1000.0 + right[0] as f64;
+        let symmetric = |left: &[u8], right: &[u8]| metric(left, right).min(metric(right, left));
+        let matrix = gradient.similarity_matrix(3, symmetric);
+        let windows = input.windows(3).collect::<Vec<_>>();
+        assert_eq!(matrix.len(), windows.len());
+        for (row, left) in windows.iter().enumerate() {
+            for (column, right) in windows.iter().enumerate() {
+                assert_eq!(matrix.get(row, column), Some(symmetric(left, right)));
+            }
+        }
+        assert_eq!(matrix.get(0, windows.len()), None);
+    }
+
+    #[test]
+    fn similarity_matrix_respects_max_width() {
+        let gradient = Gradient::with_max_width(b"abc".to_vec(), Some(1));
+        assert!(gradient.similarity_matrix(2, Equality).is_empty());
+        assert!(gradient.similarity_matrix(0, Equality).is_empty());
+        assert_eq!(gradient.similarity_matrix(1, Equality).len(), 3);
+    }
+}
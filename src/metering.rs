@@ -0,0 +1,109 @@
+use crate::{Gradient, Sliceable};
+use metrics::{Counter, Histogram};
+
+/// `Metered` is an iterator adaptor that reports throughput through
+/// the [metrics] facade, see [Gradient::metered].
+pub struct Metered<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    windows: Counter,
+    bytes: Counter,
+    widths: Histogram,
+}
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Metered<'a, G, S> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        let window = self.gradient.next()?;
+        self.windows.increment(1);
+        self.bytes.increment(core::mem::size_of_val(window) as u64);
+        self.widths.record(window.len() as f64);
+        Some(window)
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `metered` records every yielded window in the
+    /// `gradient_windows_yielded` and `gradient_bytes_yielded`
+    /// counters and the `gradient_window_width` histogram.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let count = Gradient::new(vec![1u8, 2, 3]).metered().count();
+    /// assert_eq!(count, 6);
+    /// ```
+    pub fn metered(self) -> Metered<'a, G, S> {
+        Metered {
+            gradient: self,
+            windows: metrics::counter!("gradient_windows_yielded"),
+            bytes: metrics::counter!("gradient_bytes_yielded"),
+            widths: metrics::histogram!("gradient_window_width"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use metrics::{
+        Counter, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder, SharedString,
+        Unit,
+    };
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Samples(Mutex<Vec<f64>>);
+    impl HistogramFn for Samples {
+        fn record(&self, value: f64) {
+            self.0.lock().unwrap().push(value);
+        }
+    }
+
+    #[derive(Default)]
+    struct Capture {
+        windows: Arc<AtomicU64>,
+        bytes: Arc<AtomicU64>,
+        widths: Arc<Samples>,
+    }
+    impl Recorder for Capture {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            match key.name() {
+                "gradient_windows_yielded" => Counter::from_arc(self.windows.clone()),
+                "gradient_bytes_yielded" => Counter::from_arc(self.bytes.clone()),
+                name => panic!("unexpected counter {name}"),
+            }
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            assert_eq!(key.name(), "gradient_window_width");
+            Histogram::from_arc(self.widths.clone())
+        }
+    }
+
+    #[test]
+    fn metered_records_every_window() {
+        let capture = Capture::default();
+        let gradient = Gradient::with_max_width(vec![1u32, 2, 3, 4], Some(3));
+        let total = gradient.total();
+        let bytes = gradient.clone().map(core::mem::size_of_val).sum::<usize>();
+        let widths = gradient
+            .clone()
+            .map(|window| window.len() as f64)
+            .collect::<Vec<f64>>();
+        let metered = metrics::with_local_recorder(&capture, || gradient.metered());
+        assert_eq!(metered.count(), total);
+        assert_eq!(capture.windows.load(Ordering::Relaxed), total as u64);
+        assert_eq!(capture.bytes.load(Ordering::Relaxed), bytes as u64);
+        assert_eq!(*capture.widths.0.lock().unwrap(), widths);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::rolling::{MODULUS, add, element_hashes, mul, splitmix, width_hashes};
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+impl<'a, G: Hash + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `minhash` returns a MinHash signature of `num_hashes` values
+    /// over the windows of `width`: for each of `num_hashes` universal
+    /// hash functions, the smallest hash of any window.
+    ///
+    /// The fraction of equal positions between two signatures
+    /// estimates the Jaccard [similarity](Self::similarity) of the
+    /// inputs' windows, and signatures are deterministic so they can
+    /// be stored and compared later. An input without any window of
+    /// `width` has a signature of `u64::MAX`s.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let left = Gradient::new(b"the quick brown fox jumps over the lazy dog".to_vec());
+    /// let right = Gradient::new(b"the quick brown fox jumped over a lazy dog".to_vec());
+    /// let (left, right) = (left.minhash(128, 4), right.minhash(128, 4));
+    /// let equal = left.iter().zip(&right).filter(|(l, r)| l == r).count();
+    /// assert!((40..110).contains(&equal));
+    /// ```
+    pub fn minhash(&self, num_hashes: usize, width: usize) -> Vec<u64> {
+        let mut signature = alloc::vec![u64::MAX; num_hashes];
+        if width == 0 {
+            return signature;
+        }
+        let functions = (0..num_hashes as u64)
+            .map(|index| {
+                let multiplier = splitmix(2 * index) % (MODULUS - 1) + 1;
+                (multiplier, splitmix(2 * index + 1) % MODULUS)
+            })
+            .collect::<Vec<_>>();
+        let elements = element_hashes(self.input.as_slice());
+        for hash in width_hashes(&elements, width) {
+            for (minimum, (multiplier, offset)) in signature.iter_mut().zip(&functions) {
+                *minimum = (*minimum).min(add(mul(*multiplier, hash), *offset));
+            }
+        }
+        signature
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minhash_estimates_similarity() {
+        let left = Gradient::new((0..400u32).collect::<Vec<_>>());
+        let right = Gradient::new((200..600u32).collect::<Vec<_>>());
+        let (left, right) = (left.minhash(256, 1), right.minhash(256, 1));
+        let equal = left.iter().zip(&right).filter(|(l, r)| l == r).count();
+        let estimate = equal as f64 / 256.0;
+        assert!((estimate - 1.0 / 3.0).abs() < 0.1, "{}", estimate);
+    }
+
+    #[test]
+    fn minhash_is_deterministic_and_ignores_order_of_shingles() {
+        let left = Gradient::new(b"abcabc".to_vec()).minhash(16, 3);
+        let right = Gradient::new(b"cabcab".to_vec()).minhash(16, 3);
+        assert_eq!(left, right);
+        assert_eq!(left, Gradient::new(b"abcabc".to_vec()).minhash(16, 3));
+        assert_eq!(
+            Gradient::new(b"ab".to_vec()).minhash(2, 3),
+            vec![u64::MAX; 2]
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Accumulate, Gradient, Sliceable, WindowAccumulator};
+use alloc::vec::Vec;
+
+/// `RunningMoments` is a [WindowAccumulator] of the mean and the
+/// population variance of the elements in a window.
+///
+/// It follows Welford's updates, extended to elements leaving the
+/// window, which stay accurate where subtracting running sums of
+/// squares cancels catastrophically.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RunningMoments {
+    len: usize,
+    mean: f64,
+    /// sum of squared differences from the mean
+    squares: f64,
+}
+impl<G: Copy + Into<f64>> WindowAccumulator<G> for RunningMoments {
+    type Output = (f64, f64);
+
+    fn add(&mut self, element: &G) {
+        let value = (*element).into();
+        self.len += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.len as f64;
+        self.squares += delta * (value - self.mean);
+    }
+
+    fn remove(&mut self, element: &G) {
+        let value = (*element).into();
+        self.len -= 1;
+        if self.len == 0 {
+            *self = RunningMoments::default();
+            return;
+        }
+        let delta = value - self.mean;
+        self.mean -= delta / self.len as f64;
+        self.squares -= delta * (value - self.mean);
+    }
+
+    fn value(&self) -> (f64, f64) {
+        if self.len == 0 {
+            return (0.0, 0.0);
+        }
+        (self.mean, self.squares.max(0.0) / self.len as f64)
+    }
+}
+
+/// `Statistics` yields every window of a [Gradient] along with the
+/// mean and the population variance of its elements, see
+/// [Gradient::statistics].
+#[derive(Clone, Debug)]
+pub struct Statistics<'a, G, S = Vec<G>> {
+    moments: Accumulate<'a, G, RunningMoments, S>,
+}
+impl<'a, G: Copy + Into<f64> + 'a, S: Sliceable<G>> Iterator for Statistics<'a, G, S> {
+    type Item = (&'a [G], f64, f64);
+
+    fn next(&mut self) -> Option<(&'a [G], f64, f64)> {
+        let (window, (mean, variance)) = self.moments.next()?;
+        Some((window, mean, variance))
+    }
+}
+
+impl<'a, G: Copy + Into<f64> + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `statistics` pairs every window with the mean and the
+    /// population variance of its elements, maintained incrementally
+    /// through [accumulate](Self::accumulate) as [RunningMoments].
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let statistics = Gradient::new(vec![1.0f32, 3.0, 3.0])
+    ///     .statistics()
+    ///     .map(|(_, mean, variance)| (mean, variance))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(statistics[3], (2.0, 1.0));
+    /// assert_eq!(statistics[4], (3.0, 0.0));
+    /// ```
+    pub fn statistics(self) -> Statistics<'a, G, S> {
+        Statistics {
+            moments: self.accumulate(RunningMoments::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics_match_computing_each_window() {
+        let input = [3i32, -7, 1_000_000, 2, 2, 2, 41, -8, 0, 5, 1_000_001];
+        for (window, mean, variance) in Gradient::from_slice(&input).statistics() {
+            let len = window.len() as f64;
+            let expected = window.iter().map(|value| *value as f64).sum::<f64>() / len;
+            let deviation = window
+                .iter()
+                .map(|value| (*value as f64 - expected) * (*value as f64 - expected))
+                .sum::<f64>()
+                / len;
+            assert!((mean - expected).abs() < 1e-6, "{:?}", window);
+            assert!((variance - deviation).abs() < 1e-3, "{:?}", window);
+        }
+    }
+
+    #[test]
+    fn removing_every_element_resets_the_moments() {
+        let mut moments = RunningMoments::default();
+        WindowAccumulator::<u8>::add(&mut moments, &4);
+        WindowAccumulator::<u8>::add(&mut moments, &9);
+        WindowAccumulator::<u8>::remove(&mut moments, &4);
+        assert_eq!(WindowAccumulator::<u8>::value(&moments), (9.0, 0.0));
+        WindowAccumulator::<u8>::remove(&mut moments, &9);
+        assert_eq!(moments, RunningMoments::default());
+    }
+}
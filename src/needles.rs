@@ -0,0 +1,150 @@
+use crate::{Gradient, Sliceable};
+use aho_corasick::{AhoCorasick, BuildError};
+
+/// `AnyNeedle` yields the windows of a byte [Gradient] that contain,
+/// or equal, any of a set of needles, see [Gradient::containing_any]
+/// and [Gradient::equal_to_any].
+#[derive(Clone, Debug)]
+pub struct AnyNeedle<'a, S = Vec<u8>> {
+    gradient: Gradient<'a, u8, S>,
+    /// `ends[start]`: sorted ends of the occurrences starting at `start`
+    ends: Vec<Vec<usize>>,
+    /// `earliest[start]`: earliest end of an occurrence starting at or
+    /// after `start`
+    earliest: Vec<usize>,
+    equal: bool,
+}
+impl<'a, S: Sliceable<u8>> Iterator for AnyNeedle<'a, S> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        loop {
+            let window = self.gradient.next()?;
+            let range = self.gradient.range();
+            let found = match self.equal {
+                true => self.ends[range.start].binary_search(&range.end).is_ok(),
+                false => self.earliest[range.start] <= range.end,
+            };
+            if found {
+                return Some(window);
+            }
+        }
+    }
+}
+
+impl<'a, S: Sliceable<u8>> Gradient<'a, u8, S> {
+    /// `containing_any` yields only the windows containing at least
+    /// one of `needles`, in gradient order.
+    ///
+    /// The input is scanned once by an Aho-Corasick automaton for
+    /// every, possibly overlapping, occurrence, after which telling
+    /// whether a window contains one takes `O(1)`.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let image = Gradient::with_max_width(b"\x00MZ\x90\x00".to_vec(), Some(3));
+    /// let windows = image
+    ///     .containing_any([&b"MZ"[..], b"\x90"])
+    ///     .unwrap()
+    ///     .map(Vec::from)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     windows,
+    ///     vec![
+    ///         b"\x90".to_vec(),
+    ///         b"MZ".to_vec(), b"Z\x90".to_vec(), b"\x90\x00".to_vec(),
+    ///         b"\x00MZ".to_vec(), b"MZ\x90".to_vec(), b"Z\x90\x00".to_vec(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn containing_any<P: AsRef<[u8]>>(
+        self,
+        needles: impl IntoIterator<Item = P>,
+    ) -> Result<AnyNeedle<'a, S>, BuildError> {
+        self.any_needle(needles, false)
+    }
+
+    /// `equal_to_any` yields only the windows equal to one of
+    /// `needles`, in gradient order, from a single Aho-Corasick scan
+    /// of the input.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let image = Gradient::from_slice(b"abcab");
+    /// let windows = image.equal_to_any(["ab", "bca", "x"]).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(windows, vec![&b"ab"[..], b"ab", b"bca"]);
+    /// ```
+    pub fn equal_to_any<P: AsRef<[u8]>>(
+        self,
+        needles: impl IntoIterator<Item = P>,
+    ) -> Result<AnyNeedle<'a, S>, BuildError> {
+        self.any_needle(needles, true)
+    }
+
+    fn any_needle<P: AsRef<[u8]>>(
+        self,
+        needles: impl IntoIterator<Item = P>,
+        equal: bool,
+    ) -> Result<AnyNeedle<'a, S>, BuildError> {
+        let automaton = AhoCorasick::new(needles)?;
+        let input = self.input.as_slice();
+        let mut ends = vec![Vec::new(); input.len() + 1];
+        for occurrence in automaton.find_overlapping_iter(input) {
+            if !occurrence.is_empty() {
+                ends[occurrence.start()].push(occurrence.end());
+            }
+        }
+        let mut earliest = vec![usize::MAX; input.len() + 1];
+        for start in (0..input.len()).rev() {
+            ends[start].sort_unstable();
+            let here = ends[start].first().copied().unwrap_or(usize::MAX);
+            earliest[start] = here.min(earliest[start + 1]);
+        }
+        Ok(AnyNeedle {
+            gradient: self,
+            ends,
+            earliest,
+            equal,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEEDLES: [&[u8]; 4] = [b"ab", b"b", b"cab", b"abc"];
+
+    #[test]
+    fn containing_any_agrees_with_scanning_each_window() {
+        let input = b"xabcabxxcb".to_vec();
+        let contains = |window: &[u8]| {
+            NEEDLES
+                .iter()
+                .any(|needle| window.windows(needle.len()).any(|part| part == *needle))
+        };
+        let expected = Gradient::from_slice(&input)
+            .filter(|window| contains(window))
+            .collect::<Vec<_>>();
+        let found = Gradient::from_slice(&input)
+            .containing_any(NEEDLES)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn equal_to_any_agrees_with_scanning_each_window() {
+        let input = b"xabcabxxcb".to_vec();
+        let expected = Gradient::from_slice(&input)
+            .filter(|window| NEEDLES.contains(window))
+            .collect::<Vec<_>>();
+        let found = Gradient::from_slice(&input)
+            .equal_to_any(NEEDLES)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(found, expected);
+        let none = Gradient::from_slice(&input).equal_to_any([b"z"]).unwrap();
+        assert_eq!(none.count(), 0);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Gradient, Sliceable};
+use core::hash::Hash;
+use core::ops::RangeInclusive;
+use std::collections::HashMap;
+
+impl<'a, G: Hash + Eq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `ngram_counts` counts the occurrences of every distinct window
+    /// whose width is within `widths`.
+    ///
+    /// Windows are counted by borrowing them from the input, one width
+    /// at a time, and only cloned once per distinct window.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let counts = Gradient::new(b"abab".to_vec()).ngram_counts(2..=3);
+    /// assert_eq!(counts.len(), 4);
+    /// assert_eq!(counts[&b"ab"[..]], 2);
+    /// assert_eq!(counts[&b"ba"[..]], 1);
+    /// assert_eq!(counts[&b"bab"[..]], 1);
+    /// ```
+    pub fn ngram_counts(&self, widths: RangeInclusive<usize>) -> HashMap<Vec<G>, usize>
+    where
+        G: Clone,
+    {
+        let mut counts = HashMap::new();
+        self.for_each_ngram_count(widths, |window, count| {
+            counts.insert(window.to_vec(), count);
+        });
+        counts
+    }
+
+    /// `for_each_ngram_count` calls `f` with every distinct window
+    /// whose width is within `widths` and its number of occurrences,
+    /// narrowest first, without allocating a window.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut repeated = Vec::new();
+    /// Gradient::new(b"to be or not to be".to_vec()).for_each_ngram_count(5..=5, |window, count| {
+    ///     if count > 1 {
+    ///         repeated.push(String::from_utf8(window.to_vec()).unwrap());
+    ///     }
+    /// });
+    /// assert_eq!(repeated, vec!["to be".to_string()]);
+    /// ```
+    pub fn for_each_ngram_count<F: FnMut(&[G], usize)>(
+        &self,
+        widths: RangeInclusive<usize>,
+        mut f: F,
+    ) {
+        let input = self.input.as_slice();
+        let mut counts: HashMap<&[G], usize> = HashMap::new();
+        for width in (*widths.start()).max(1)..=(*widths.end()).min(self.widest()) {
+            counts.clear();
+            for window in input.windows(width) {
+                *counts.entry(window).or_default() += 1;
+            }
+            for (window, count) in counts.drain() {
+                f(window, count);
+            }
+        }
+    }
+
+    /// `merge_candidates` ranks every distinct window whose width is
+    /// within `widths` by number of occurrences, most frequent first,
+    /// as byte-pair-encoding training needs with `widths` of `2..=2`
+    /// for adjacent pairs or wider for short-window statistics.
+    ///
+    /// Equally frequent windows rank narrowest first, then by first
+    /// occurrence, so rankings are deterministic.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let tokens = Gradient::new(b"aaabdaaabac".to_vec());
+    /// let candidates = tokens.merge_candidates(2..=2);
+    /// assert_eq!(candidates[..3], [(&b"aa"[..], 4), (&b"ab"[..], 2), (&b"bd"[..], 1)]);
+    /// ```
+    pub fn merge_candidates(&self, widths: RangeInclusive<usize>) -> Vec<(&[G], usize)> {
+        let input = self.input.as_slice();
+        let mut counts: HashMap<&[G], (usize, usize)> = HashMap::new();
+        for width in (*widths.start()).max(1)..=(*widths.end()).min(self.widest()) {
+            for (start, window) in input.windows(width).enumerate() {
+                counts.entry(window).or_insert((0, start)).0 += 1;
+            }
+        }
+        let mut ranked = counts.into_iter().collect::<Vec<_>>();
+        ranked.sort_by_key(|(window, (count, first))| {
+            (core::cmp::Reverse(*count), window.len(), *first)
+        });
+        ranked
+            .into_iter()
+            .map(|(window, (count, _))| (window, count))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ngram_counts_match_collect_then_count() {
+        let input = "the cat sat on the mat".split(' ').collect::<Vec<_>>();
+        let mut expected = HashMap::new();
+        for window in Gradient::from_slice(&input) {
+            *expected.entry(window.to_vec()).or_insert(0) += 1;
+        }
+        let counts = Gradient::from_slice(&input).ngram_counts(1..=input.len());
+        assert_eq!(counts, expected);
+        assert_eq!(counts[&vec!["the"]], 2);
+    }
+
+    #[test]
+    fn merge_candidates_rank_by_frequency() {
+        let tokens = vec!["lo", "w", "lo", "w", "er", "lo", "w", "est"];
+        let gradient = Gradient::from_slice(&tokens);
+        let candidates = gradient.merge_candidates(2..=3);
+        assert_eq!(candidates[0], (&["lo", "w"][..], 3));
+        assert_eq!(candidates[1], (&["w", "lo"][..], 1));
+        assert_eq!(candidates[2], (&["w", "er"][..], 1));
+        assert_eq!(candidates[5], (&["lo", "w", "lo"][..], 1));
+        let total = candidates.iter().map(|(_, count)| count).sum::<usize>();
+        assert_eq!(total, 7 + 6);
+        assert!(gradient.merge_candidates(9..=9).is_empty());
+    }
+
+    #[test]
+    fn ngram_counts_respect_max_width() {
+        let gradient = Gradient::with_max_width(vec![1, 1, 1], Some(2));
+        let counts = gradient.ngram_counts(0..=9);
+        assert_eq!(counts, HashMap::from([(vec![1], 3), (vec![1, 1], 2)]));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{Gradient, Sliceable, windows_narrower_than_u128};
+use core::marker::PhantomData;
+
+/// `WidthAscending` yields every window of a width, by ascending
+/// start, before any wider window: the default order of a [Gradient].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WidthAscending;
+
+/// `WidthDescending` yields the widest windows first, each width by
+/// ascending start.
+///
+/// ```
+/// use gradient_slice::{Gradient, WidthDescending};
+/// let gradient: Gradient<u8, &[u8], WidthDescending> = Gradient::from_slice(b"abc").ordered();
+/// assert_eq!(gradient.collect::<Vec<_>>(), vec![&b"abc"[..], b"ab", b"bc", b"a", b"b", b"c"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WidthDescending;
+
+/// `StartAscending` yields every window starting at an element, by
+/// ascending width, before any window starting after it.
+///
+/// ```
+/// use gradient_slice::{Gradient, StartAscending};
+/// let gradient: Gradient<u8, &[u8], StartAscending> = Gradient::from_slice(b"abc").ordered();
+/// assert_eq!(gradient.collect::<Vec<_>>(), vec![&b"a"[..], b"ab", b"abc", b"b", b"bc", b"c"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StartAscending;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::WidthAscending {}
+    impl Sealed for super::WidthDescending {}
+    impl Sealed for super::StartAscending {}
+}
+
+/// `Order` is implemented by the zero-sized types naming the orders
+/// a [Gradient] can yield its windows in, each with its own `next`.
+pub trait Order: sealed::Sealed + Default {}
+impl Order for WidthAscending {}
+impl Order for WidthDescending {}
+impl Order for StartAscending {}
+
+impl<'a, G: 'a, S: Sliceable<G>, O> Gradient<'a, G, S, O> {
+    /// `ordered` turns the [Gradient](Self) into one yielding its
+    /// windows in order `P`, starting over from the first of them.
+    pub fn ordered<P: Order>(self) -> Gradient<'a, G, S, P> {
+        Gradient {
+            input: self.input,
+            start: 0,
+            end: 0,
+            width: 1,
+            wide: true,
+            max_width: self.max_width,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// `(n, Some(n))` for `n` windows left, unless `n` overflows a `usize`
+fn exact(remaining: u128) -> (usize, Option<usize>) {
+    match usize::try_from(remaining) {
+        Ok(remaining) => (remaining, Some(remaining)),
+        Err(_) => (usize::MAX, None),
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Gradient<'a, G, S, WidthDescending> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        if self.end == 0 {
+            (self.start, self.width) = (0, self.widest());
+        } else if self.end < self.len() {
+            self.start += 1;
+        } else if self.width > 1 {
+            (self.start, self.width) = (0, self.width - 1);
+        } else {
+            return None;
+        }
+        if self.width == 0 {
+            return None;
+        }
+        self.end = self.start + self.width;
+        Some(self.window())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.end == 0 {
+            return exact(self.total_u128());
+        }
+        let narrower = windows_narrower_than_u128(self.len(), self.width);
+        exact((self.len() - self.end) as u128 + narrower)
+    }
+}
+
+/// number of windows starting at each of the last `starts` elements,
+/// up to `widest` wide
+fn windows_starting_in_last(starts: usize, widest: usize) -> u128 {
+    let (starts, widest) = (starts as u128, widest as u128);
+    if starts <= widest {
+        return starts * (starts + 1) / 2;
+    }
+    widest * (widest + 1) / 2 + (starts - widest) * widest
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Gradient<'a, G, S, StartAscending> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        let widest = self.widest();
+        if self.end == 0 {
+            (self.start, self.width) = (0, 1);
+        } else if self.width < widest && self.end < self.len() {
+            self.width += 1;
+        } else if self.start + 1 < self.len() {
+            (self.start, self.width) = (self.start + 1, 1);
+        } else {
+            return None;
+        }
+        if widest == 0 {
+            return None;
+        }
+        self.end = self.start + self.width;
+        Some(self.window())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.end == 0 {
+            return exact(self.total_u128());
+        }
+        let widest = self.widest();
+        let here = widest.min(self.len() - self.start) - self.width;
+        exact(here as u128 + windows_starting_in_last(self.len() - self.start - 1, widest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use core::ops::Range;
+
+    fn ranges<'a, O>(mut gradient: Gradient<'a, u8, &'a [u8], O>) -> Vec<Range<usize>>
+    where
+        Gradient<'a, u8, &'a [u8], O>: Iterator<Item = &'a [u8]>,
+    {
+        let mut ranges = Vec::new();
+        let mut remaining = gradient.size_hint();
+        while let Some(window) = gradient.next() {
+            assert_eq!(remaining.1, Some(remaining.0));
+            remaining = gradient.size_hint();
+            assert_eq!(window.len(), gradient.width());
+            ranges.push(gradient.range());
+        }
+        assert_eq!(remaining, (0, Some(0)));
+        assert_eq!(gradient.next(), None);
+        ranges
+    }
+
+    #[test]
+    fn orders_permute_the_same_windows() {
+        let input = b"abcdef";
+        for len in 0..=input.len() {
+            for max_width in [None, Some(0), Some(1), Some(3), Some(9)] {
+                let gradient = Gradient {
+                    max_width,
+                    ..Gradient::from_slice(&input[..len])
+                };
+                let mut ascending = ranges(gradient.clone());
+                let mut descending = ranges(gradient.clone().ordered::<WidthDescending>());
+                let mut by_start = ranges(gradient.clone().ordered::<StartAscending>());
+                assert_eq!(ascending.len(), gradient.total());
+                let mut by_width = ascending.clone();
+                by_width.sort_by_key(|range| core::cmp::Reverse(range.len()));
+                assert_eq!(descending, by_width);
+                by_width.sort_by_key(|range| (range.start, range.len()));
+                assert_eq!(by_start, by_width);
+                for windows in [&mut ascending, &mut descending, &mut by_start] {
+                    windows.sort_by_key(|range| (range.start, range.end));
+                }
+                assert_eq!((&ascending, &ascending), (&descending, &by_start));
+            }
+        }
+    }
+}
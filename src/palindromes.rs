@@ -0,0 +1,146 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+
+/// `Palindromes` yields only the windows of a [Gradient] that read
+/// the same forwards and backwards, see [Gradient::palindromes].
+#[derive(Clone, Debug)]
+pub struct Palindromes<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    /// `odd[c]`: radius, counting the center, of the widest odd
+    /// palindrome centered at `c`
+    odd: Vec<usize>,
+    /// `even[c]`: half the width of the widest even palindrome whose
+    /// right half starts at `c`
+    even: Vec<usize>,
+    widest: usize,
+}
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Palindromes<'a, G, S> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        loop {
+            if self.gradient.width() > self.widest {
+                return None;
+            }
+            let window = self.gradient.next()?;
+            let (start, width) = (self.gradient.start(), window.len());
+            let center = start + width / 2;
+            let palindrome = match width % 2 {
+                1 => self.odd[center] > width / 2,
+                _ => self.even[center] >= width / 2,
+            };
+            if palindrome {
+                return Some(window);
+            }
+        }
+    }
+}
+
+/// Manacher's algorithm, radii of the odd and even palindromes
+/// centered at every position in `O(n)`
+fn manacher<G: PartialEq>(input: &[G]) -> (Vec<usize>, Vec<usize>) {
+    let len = input.len();
+    let mut odd = alloc::vec![0; len];
+    let (mut left, mut right) = (0, 0);
+    for center in 0..len {
+        let mut radius = match center < right {
+            true => odd[left + right - 1 - center].min(right - center),
+            false => 1,
+        };
+        while center >= radius
+            && center + radius < len
+            && input[center - radius] == input[center + radius]
+        {
+            radius += 1;
+        }
+        odd[center] = radius;
+        if center + radius > right {
+            (left, right) = (center + 1 - radius, center + radius);
+        }
+    }
+    let mut even = alloc::vec![0; len];
+    let (mut left, mut right) = (0, 0);
+    for center in 0..len {
+        let mut radius = match center < right {
+            true => even[left + right - center].min(right - center),
+            false => 0,
+        };
+        while center + radius < len
+            && center > radius
+            && input[center - radius - 1] == input[center + radius]
+        {
+            radius += 1;
+        }
+        even[center] = radius;
+        if center + radius > right {
+            (left, right) = (center - radius, center + radius);
+        }
+    }
+    (odd, even)
+}
+
+impl<'a, G: PartialEq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `palindromes` yields only the windows that read the same
+    /// forwards and backwards, in gradient order.
+    ///
+    /// Manacher's algorithm finds the widest palindrome around every
+    /// center in `O(n)` upfront, so each window is then checked in
+    /// `O(1)`, and iteration ends past the widest palindrome.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let palindromes = Gradient::from_slice(b"abaab").palindromes().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     palindromes,
+    ///     vec![&b"a"[..], b"b", b"a", b"a", b"b", b"aa", b"aba", b"baab"]
+    /// );
+    /// ```
+    pub fn palindromes(self) -> Palindromes<'a, G, S> {
+        let (odd, even) = manacher(self.input.as_slice());
+        let widest_odd = odd.iter().map(|radius| 2 * radius - 1).max();
+        let widest_even = even.iter().map(|radius| 2 * radius).max();
+        Palindromes {
+            widest: widest_odd.max(widest_even).unwrap_or(0),
+            gradient: self,
+            odd,
+            even,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reads_the_same(window: &[u8]) -> bool {
+        window.iter().eq(window.iter().rev())
+    }
+
+    #[test]
+    fn palindromes_agree_with_checking_each_window() {
+        for input in [
+            "abacabadabacaba",
+            "aaaa",
+            "ab",
+            "",
+            "racecar xyzzyx",
+            "abba",
+        ] {
+            let input = input.as_bytes();
+            let expected = Gradient::from_slice(input)
+                .filter(|window| reads_the_same(window))
+                .collect::<Vec<_>>();
+            let palindromes = Gradient::from_slice(input)
+                .palindromes()
+                .collect::<Vec<_>>();
+            assert_eq!(palindromes, expected);
+        }
+    }
+
+    #[test]
+    fn manacher_radii() {
+        let (odd, even) = manacher(b"abaaba");
+        assert_eq!(odd, vec![1, 2, 1, 1, 2, 1]);
+        assert_eq!(even, vec![0, 0, 0, 3, 0, 0]);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{Gradient, Sliceable};
+use rayon::prelude::*;
+
+impl<'a, G: Sync + 'a, S: Sliceable<G> + Sync> Gradient<'a, G, S> {
+    /// `par_fold_by_width` maps every window with `map` and reduces
+    /// the results with `reduce`, running each width pass as an
+    /// independent [rayon] job.
+    ///
+    /// Windows are reduced in the order the [Gradient](Self) yields
+    /// them, so `reduce` only needs to be associative. The whole
+    /// gradient is folded regardless of how far it has been iterated,
+    /// and `None` is returned when it yields no windows.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let sum = Gradient::new(vec![1, 2, 3])
+    ///     .par_fold_by_width(|window| window.iter().sum::<i32>(), |a, b| a + b);
+    /// assert_eq!(sum, Some(20));
+    /// ```
+    pub fn par_fold_by_width<T, M, R>(&self, map: M, reduce: R) -> Option<T>
+    where
+        T: Send,
+        M: Fn(&[G]) -> T + Sync,
+        R: Fn(T, T) -> T + Sync,
+    {
+        (1..=self.widest())
+            .into_par_iter()
+            .filter_map(|width| {
+                self.input
+                    .as_slice()
+                    .windows(width)
+                    .map(&map)
+                    .reduce(&reduce)
+            })
+            .reduce_with(&reduce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn par_fold_by_width_keeps_gradient_order() {
+        let gradient = Gradient::with_max_width(" abc ".chars().collect(), Some(2));
+        let folded = gradient.par_fold_by_width(
+            |window| window.iter().collect::<String>(),
+            |a, b| format!("{a}|{b}"),
+        );
+        assert_eq!(folded.as_deref(), Some(" |a|b|c| | a|ab|bc|c "));
+    }
+
+    #[test]
+    fn par_fold_by_width_empty() {
+        let folded =
+            Gradient::new(Vec::<u8>::new()).par_fold_by_width(|window| window.len(), |a, b| a + b);
+        assert_eq!(folded, None);
+    }
+}
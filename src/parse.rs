@@ -0,0 +1,90 @@
+use crate::{Gradient, Sliceable};
+use core::ops::Range;
+use nom::Parser;
+
+impl<'a, S: Sliceable<u8>> Gradient<'a, u8, S> {
+    /// `longest_parse` returns the range of the widest window that
+    /// `parser` consumes entirely, along with the parsed output; the
+    /// first such window wins among equally wide ones.
+    ///
+    /// Starts are anchored in ascending order and, at each start,
+    /// windows grow from one element wider than the best parse so far
+    /// up to `max_width`, so narrower candidates are never parsed once
+    /// a wider one succeeded. Each window is parsed as complete input,
+    /// and any error, recoverable or not, just rules the window out.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// use nom::Parser;
+    /// use nom::character::complete::{char, digit1};
+    /// use nom::sequence::separated_pair;
+    /// let gradient = Gradient::new(b"x=1, 12=345;".to_vec());
+    /// let (range, (key, value)) = gradient
+    ///     .longest_parse(separated_pair(digit1::<_, ()>, char('='), digit1))
+    ///     .unwrap();
+    /// assert_eq!(range, 5..11);
+    /// assert_eq!((key, value), (&b"12"[..], &b"345"[..]));
+    /// ```
+    pub fn longest_parse<P: Parser<&'a [u8]>>(
+        &'a self,
+        mut parser: P,
+    ) -> Option<(Range<usize>, P::Output)> {
+        let input = self.input.as_slice();
+        let widest = self.widest();
+        let mut longest: Option<(Range<usize>, P::Output)> = None;
+        for start in 0..input.len() {
+            let narrowest = longest.as_ref().map_or(1, |(range, _)| range.len() + 1);
+            for width in narrowest..=widest.min(input.len() - start) {
+                let range = start..start + width;
+                if let Ok((rest, output)) = parser.parse_complete(&input[range.clone()]) {
+                    if rest.is_empty() {
+                        longest = Some((range, output));
+                    }
+                }
+            }
+        }
+        longest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::bytes::complete::tag;
+    use nom::multi::many1;
+
+    #[test]
+    fn longest_parse_agrees_with_parsing_every_window() {
+        let input = b"ababxabababab".to_vec();
+        for max_width in [None, Some(3), Some(7)] {
+            let gradient = Gradient::with_max_width(input.clone(), max_width);
+            let mut expected = None;
+            for window in
+                Gradient::from_slice(&input).filter(|window| window.len() <= gradient.widest())
+            {
+                let parsed = many1(tag::<_, _, ()>(&b"ab"[..])).parse_complete(window);
+                let wider = expected
+                    .as_ref()
+                    .is_none_or(|range: &Range<usize>| range.len() < window.len());
+                if wider && parsed.is_ok_and(|(rest, _)| rest.is_empty()) {
+                    let start = window.as_ptr() as usize - input.as_ptr() as usize;
+                    expected = Some(start..start + window.len());
+                }
+            }
+            let found = gradient.longest_parse(many1(tag::<_, _, ()>(&b"ab"[..])));
+            assert_eq!(
+                found.map(|(range, output)| (range, output.len())),
+                expected.map(|range| {
+                    let pairs = range.len() / 2;
+                    (range, pairs)
+                })
+            );
+        }
+        let gradient = Gradient::new(b"xyz".to_vec());
+        assert!(
+            gradient
+                .longest_parse(tag::<_, _, ()>(&b"ab"[..]))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Gradient, Sliceable};
+use std::sync::mpsc::{Receiver, sync_channel};
+use std::thread::{self, JoinHandle};
+
+/// `Prefetch` yields values computed from upcoming windows on a
+/// worker thread, see [Gradient::prefetch].
+#[derive(Debug)]
+pub struct Prefetch<T> {
+    receiver: Receiver<T>,
+    worker: Option<JoinHandle<()>>,
+}
+impl<T> Iterator for Prefetch<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if let Ok(value) = self.receiver.recv() {
+            return Some(value);
+        }
+        if let Some(worker) = self.worker.take() {
+            if let Err(panic) = worker.join() {
+                std::panic::resume_unwind(panic);
+            }
+        }
+        None
+    }
+}
+
+impl<G: Send + Sync + 'static, S: Sliceable<G> + Send + 'static> Gradient<'static, G, S> {
+    /// `prefetch` moves the [Gradient](Self) to a worker thread that
+    /// applies `map` to its windows ahead of the consumer, keeping up
+    /// to `depth` results ready, and yields them in gradient order.
+    ///
+    /// The worker stops once the returned [Prefetch] is dropped. It is
+    /// spawned with a channel buffering `depth` results, both
+    /// allocated up front.
+    /// A panic in `map` is resumed on the consuming thread.
+    ///
+    /// Panics if `depth` is zero.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let sums = Gradient::new(vec![1, 2, 3])
+    ///     .prefetch(|window| window.iter().sum::<i32>(), 2)
+    ///     .collect::<Vec<i32>>();
+    /// assert_eq!(sums, vec![1, 2, 3, 3, 5, 6]);
+    /// ```
+    pub fn prefetch<T, F>(self, map: F, depth: usize) -> Prefetch<T>
+    where
+        T: Send + 'static,
+        F: FnMut(&[G]) -> T + Send + 'static,
+    {
+        assert!(depth > 0, "depth must be greater than zero");
+        let (sender, receiver) = sync_channel(depth);
+        let worker = thread::spawn(move || {
+            for value in self.map(map) {
+                if sender.send(value).is_err() {
+                    return;
+                }
+            }
+        });
+        Prefetch {
+            receiver,
+            worker: Some(worker),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::{RecvTimeoutError, channel};
+    use std::time::Duration;
+
+    #[test]
+    fn prefetch_stays_within_depth() {
+        let (computed, calls) = channel();
+        let mut prefetch = Gradient::new(" abc ".chars().collect()).prefetch(
+            move |window| {
+                computed.send(()).unwrap();
+                window.iter().collect::<String>()
+            },
+            2,
+        );
+        assert_eq!(prefetch.next().as_deref(), Some(" "));
+        // one window consumed, two buffered and one blocked in `send`
+        for _ in 0..4 {
+            calls.recv_timeout(Duration::from_secs(10)).unwrap();
+        }
+        assert_eq!(
+            calls.recv_timeout(Duration::from_millis(50)),
+            Err(RecvTimeoutError::Timeout)
+        );
+        assert_eq!(prefetch.count(), 14);
+        assert_eq!(calls.try_iter().count(), 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn prefetch_resumes_worker_panics() {
+        Gradient::new(vec![1, 2, 3])
+            .prefetch(|window| assert!(window.len() < 2, "boom"), 1)
+            .for_each(drop);
+    }
+}
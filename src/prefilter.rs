@@ -0,0 +1,174 @@
+use crate::{Gradient, Sliceable};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use memchr::memmem::Finder;
+
+/// `Prefilter` is what every window of a [Prefiltered] gradient must
+/// contain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Prefilter<'n> {
+    /// a byte, found by `memchr`
+    Byte(u8),
+    /// a byte sequence, found by `memmem`
+    Needle(&'n [u8]),
+}
+
+#[derive(Clone, Debug)]
+enum Search<'n> {
+    Byte(u8),
+    Needle(Box<Finder<'n>>),
+}
+impl Search<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Search::Byte(_) => 1,
+            Search::Needle(finder) => finder.needle().len(),
+        }
+    }
+
+    /// start of the first occurrence at or after `from`
+    fn find(&self, input: &[u8], from: usize) -> Option<usize> {
+        let found = match self {
+            Search::Byte(byte) => memchr::memchr(*byte, &input[from..]),
+            Search::Needle(finder) => finder.find(&input[from..]),
+        };
+        found.map(|offset| from + offset)
+    }
+}
+
+/// `Prefiltered` yields the windows of a byte [Gradient] containing a
+/// [Prefilter], see [Gradient::prefiltered].
+#[derive(Clone, Debug)]
+pub struct Prefiltered<'a, 'n, S = Vec<u8>> {
+    gradient: Gradient<'a, u8, S>,
+    search: Search<'n>,
+    /// where the last search started and what it found, reused while
+    /// the window starts stay at or before the occurrence
+    found: Option<(usize, Option<usize>)>,
+}
+impl<'a, S: Sliceable<u8>> Prefiltered<'a, '_, S> {
+    /// first occurrence starting at or after `start`
+    fn occurrence(&mut self, start: usize) -> Option<usize> {
+        match self.found {
+            Some((from, found)) if from <= start && found.is_none_or(|at| at >= start) => found,
+            _ => {
+                let found = self.search.find(self.gradient.input.as_slice(), start);
+                self.found = Some((start, found));
+                found
+            }
+        }
+    }
+
+    /// moves the gradient so that its next window is `start..start +
+    /// width`, the first one of the next width when that window would
+    /// end past the input, or past the last window
+    fn seek(&mut self, width: usize, start: usize) {
+        let (len, gradient) = (self.gradient.len(), &mut self.gradient);
+        let (width, start) = match start + width > len {
+            true => (width + 1, 0),
+            false => (width, start),
+        };
+        if width > gradient.widest() {
+            (gradient.width, gradient.start, gradient.end, gradient.wide) = (len, 0, len, false);
+            return;
+        }
+        (gradient.width, gradient.end, gradient.wide) = (width, start + width - 1, true);
+    }
+}
+impl<'a, S: Sliceable<u8>> Iterator for Prefiltered<'a, '_, S> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        let needle = self.search.len();
+        loop {
+            let window = self.gradient.next()?;
+            let range = self.gradient.range();
+            if window.len() < needle {
+                self.seek(needle, 0);
+                continue;
+            }
+            match self.occurrence(range.start) {
+                Some(at) if at + needle <= range.end => return Some(window),
+                Some(at) => self.seek(window.len(), at + needle - window.len()),
+                None => self.seek(window.len() + 1, 0),
+            }
+        }
+    }
+}
+
+impl<'a, S: Sliceable<u8>> Gradient<'a, u8, S> {
+    /// `prefiltered` yields only the remaining windows containing
+    /// `prefilter`, in gradient order, to apply before costlier
+    /// predicates.
+    ///
+    /// Occurrences are located with `memchr` or `memmem`, and windows
+    /// that can't reach the next one are skipped over rather than
+    /// examined: past the last occurrence, the rest of a width is
+    /// skipped altogether.
+    ///
+    /// ```
+    /// use gradient_slice::{Gradient, Prefilter};
+    /// let gradient = Gradient::with_max_width(b"GET / HTTP/1.1\r\n".to_vec(), Some(4));
+    /// let windows = gradient
+    ///     .prefiltered(Prefilter::Needle(b"\r\n"))
+    ///     .map(|window| window.to_vec())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(windows, vec![b"\r\n".to_vec(), b"1\r\n".to_vec(), b".1\r\n".to_vec()]);
+    /// ```
+    pub fn prefiltered<'n>(self, prefilter: Prefilter<'n>) -> Prefiltered<'a, 'n, S> {
+        let search = match prefilter {
+            Prefilter::Byte(byte) => Search::Byte(byte),
+            Prefilter::Needle(needle) => Search::Needle(Box::new(Finder::new(needle))),
+        };
+        Prefiltered {
+            gradient: self,
+            search,
+            found: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefiltered_agrees_with_filtering_every_window() {
+        let input = b"aXbaaXXaab\x00Xa".to_vec();
+        let prefilters = [
+            Prefilter::Byte(b'X'),
+            Prefilter::Byte(b'z'),
+            Prefilter::Needle(b"aa"),
+            Prefilter::Needle(b"Xa"),
+            Prefilter::Needle(b""),
+            Prefilter::Needle(b"aXbaaXXaab\x00Xa!"),
+        ];
+        for prefilter in prefilters {
+            let needle = match prefilter {
+                Prefilter::Byte(byte) => vec![byte],
+                Prefilter::Needle(needle) => needle.to_vec(),
+            };
+            for max_width in [None, Some(1), Some(4)] {
+                for skip in [0, 5] {
+                    let contains = |window: &[u8]| {
+                        needle.is_empty() || window.windows(needle.len()).any(|part| part == needle)
+                    };
+                    let mut gradient = Gradient::with_max_width(input.clone(), max_width);
+                    if skip > 0 {
+                        gradient.nth(skip - 1);
+                    }
+                    let expected = gradient
+                        .clone()
+                        .filter(|window| contains(window))
+                        .map(Vec::from)
+                        .collect::<Vec<_>>();
+                    let found = gradient
+                        .prefiltered(prefilter)
+                        .map(Vec::from)
+                        .collect::<Vec<_>>();
+                    assert_eq!(found, expected, "{:?} {:?} {}", prefilter, max_width, skip);
+                }
+            }
+        }
+    }
+}
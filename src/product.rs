@@ -0,0 +1,228 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+
+/// `Product` yields pairs of windows of two [Gradient]s, see
+/// [Gradient::product] and [Gradient::product_equal_widths].
+#[derive(Clone, Debug)]
+pub struct Product<'a, G, H, S = Vec<G>, T = Vec<H>> {
+    first: Gradient<'a, G, S>,
+    second: Gradient<'a, H, T>,
+    current: Option<&'a [G]>,
+    equal_widths: bool,
+}
+impl<'a, G: 'a, H: 'a, S: Sliceable<G>, T: Sliceable<H>> Product<'a, G, H, S, T> {
+    /// windows of the second gradient left to pair with the current
+    /// window of the first
+    fn paired_left(&self) -> Option<u128> {
+        let Some(current) = self.current else {
+            return Some(0);
+        };
+        if !self.equal_widths {
+            return self.second.try_count().ok().map(|count| count as u128);
+        }
+        Some(match pending(&self.second) {
+            Some((width, count)) if width == current.len() => count as u128,
+            _ => 0,
+        })
+    }
+
+    /// pairs of windows the first gradient has yet to yield
+    fn unpaired_left(&self) -> Option<u128> {
+        if !self.equal_widths {
+            let first = self.first.try_count().ok()? as u128;
+            return first.checked_mul(self.second.total_u128());
+        }
+        let Some((width, count)) = pending(&self.first) else {
+            return Some(0);
+        };
+        let widest = self.first.widest().min(self.second.widest());
+        if width > widest {
+            return Some(0);
+        }
+        let here = (count as u128).checked_mul((self.second.len() - width + 1) as u128)?;
+        let wider = products_of_counts(self.first.len(), self.second.len(), width + 1, widest)?;
+        here.checked_add(wider)
+    }
+}
+impl<'a, G: 'a, H: 'a, S: Sliceable<G>, T: Sliceable<H>> Iterator for Product<'a, G, H, S, T> {
+    type Item = (&'a [G], &'a [H]);
+
+    fn next(&mut self) -> Option<(&'a [G], &'a [H])> {
+        loop {
+            if let Some(current) = self.current {
+                if !self.equal_widths || self.second.next_width() == Some(current.len()) {
+                    if let Some(paired) = self.second.next() {
+                        return Some((current, paired));
+                    }
+                }
+            }
+            let current = self.first.next()?;
+            self.current = Some(current);
+            self.second.rewind();
+            if self.equal_widths {
+                let width = current.len();
+                (self.second.width, self.second.end) = (width, width - 1);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self
+            .paired_left()
+            .zip(self.unpaired_left())
+            .and_then(|(paired, unpaired)| paired.checked_add(unpaired))
+            .and_then(|left| usize::try_from(left).ok());
+        match left {
+            Some(left) => (left, Some(left)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+/// width of the next window of `gradient` and how many windows of
+/// that width it has left to yield
+fn pending<G, S: Sliceable<G>>(gradient: &Gradient<'_, G, S>) -> Option<(usize, usize)> {
+    let width = gradient.next_width()?;
+    let left = match gradient.wide {
+        true => gradient.len() - gradient.end,
+        false => gradient.len() - width + 1,
+    };
+    Some((width, left))
+}
+
+/// sum over every width from `low` to `high` of the products of the
+/// numbers of windows of that width over inputs of `left` and `right`
+/// elements, `None` if it overflows
+fn products_of_counts(left: usize, right: usize, low: usize, high: usize) -> Option<u128> {
+    if low > high {
+        return Some(0);
+    }
+    // (left + 1 - width) * (right + 1 - width) summed as a quadratic in width
+    let (a, b) = (left as u128 + 1, right as u128 + 1);
+    let sum = |n: u128| n * (n + 1) / 2;
+    let sum_of_squares = |n: u128| {
+        n.checked_mul(n + 1)?
+            .checked_mul(2 * n + 1)
+            .map(|product| product / 6)
+    };
+    let (low, high) = (low as u128, high as u128);
+    let widths = high - low + 1;
+    let linear = sum(high) - sum(low - 1);
+    let quadratic = sum_of_squares(high)? - sum_of_squares(low - 1)?;
+    widths
+        .checked_mul(a.checked_mul(b)?)?
+        .checked_add(quadratic)?
+        .checked_sub((a + b).checked_mul(linear)?)
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `product` pairs every window of the [Gradient](Self) with
+    /// every window of `other`, in the order of this gradient and then
+    /// of `other`, without collecting either of them.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let pairs = Gradient::from_slice(b"ab").product(Gradient::from_slice(b"x"));
+    /// assert_eq!(pairs.size_hint(), (3, Some(3)));
+    /// assert_eq!(
+    ///     pairs.collect::<Vec<_>>(),
+    ///     vec![(&b"a"[..], &b"x"[..]), (b"b", b"x"), (b"ab", b"x")]
+    /// );
+    /// ```
+    pub fn product<H: 'a, T: Sliceable<H>>(
+        self,
+        other: Gradient<'a, H, T>,
+    ) -> Product<'a, G, H, S, T> {
+        Product {
+            first: self,
+            second: other,
+            current: None,
+            equal_widths: false,
+        }
+    }
+
+    /// `product_equal_widths` is [product](Self::product) restricted
+    /// to the pairs of windows as wide as each other.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let pairs = Gradient::from_slice(b"ab").product_equal_widths(Gradient::from_slice(b"xyz"));
+    /// assert_eq!(pairs.size_hint(), (8, Some(8)));
+    /// assert_eq!(
+    ///     pairs.map(|(a, b)| [a, b].concat()).collect::<Vec<_>>(),
+    ///     [&b"ax"[..], b"ay", b"az", b"bx", b"by", b"bz", b"abxy", b"abyz"]
+    /// );
+    /// ```
+    pub fn product_equal_widths<H: 'a, T: Sliceable<H>>(
+        self,
+        other: Gradient<'a, H, T>,
+    ) -> Product<'a, G, H, S, T> {
+        Product {
+            equal_widths: true,
+            ..self.product(other)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_matches_nested_loops() {
+        let (left, right) = (b"abcd".to_vec(), b"xyz".to_vec());
+        for (max_left, max_right) in [
+            (None, None),
+            (Some(2), None),
+            (None, Some(1)),
+            (Some(0), None),
+        ] {
+            let first = Gradient {
+                max_width: max_left,
+                ..Gradient::from_slice(&left)
+            };
+            let second = Gradient {
+                max_width: max_right,
+                ..Gradient::from_slice(&right)
+            };
+            for equal_widths in [false, true] {
+                let expected = first
+                    .clone()
+                    .flat_map(|a| second.clone().map(move |b| (a, b)))
+                    .filter(|(a, b)| !equal_widths || a.len() == b.len())
+                    .collect::<Vec<_>>();
+                let mut product = match equal_widths {
+                    true => first.clone().product_equal_widths(second.clone()),
+                    false => first.clone().product(second.clone()),
+                };
+                let mut yielded = Vec::new();
+                loop {
+                    let left = expected.len() - yielded.len();
+                    assert_eq!(product.size_hint(), (left, Some(left)));
+                    match product.next() {
+                        Some(pair) => yielded.push(pair),
+                        None => break,
+                    }
+                }
+                assert_eq!(yielded, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn products_of_counts_matches_summing() {
+        for (left, right) in [(3, 5), (10, 4), (6, 6)] {
+            for low in 1..=4 {
+                for high in low - 1..=left.min(right) {
+                    let expected = (low..=high)
+                        .map(|width| (left + 1 - width) * (right + 1 - width))
+                        .sum::<usize>();
+                    assert_eq!(
+                        products_of_counts(left, right, low, high),
+                        Some(expected as u128)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{Gradient, Sliceable, WindowAccumulator};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// `ByteProfile` is a [WindowAccumulator] counting every byte value in
+/// a window, see [Gradient::frequency_profiles].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ByteProfile {
+    counts: [usize; 256],
+    len: usize,
+}
+impl Default for ByteProfile {
+    fn default() -> ByteProfile {
+        ByteProfile {
+            counts: [0; 256],
+            len: 0,
+        }
+    }
+}
+impl ByteProfile {
+    /// `count` of `byte` in the window
+    pub fn count(&self, byte: u8) -> usize {
+        self.counts[byte as usize]
+    }
+
+    /// `counts` of every byte value in the window, indexed by value
+    pub fn counts(&self) -> &[usize; 256] {
+        &self.counts
+    }
+
+    /// `frequency` of `byte` in the window, from `0.0` to `1.0`
+    pub fn frequency(&self, byte: u8) -> f64 {
+        frequency(self.count(byte), self.len)
+    }
+
+    /// `len` of the window
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+impl WindowAccumulator<u8> for ByteProfile {
+    type Output = [usize; 256];
+
+    fn add(&mut self, byte: &u8) {
+        self.counts[*byte as usize] += 1;
+        self.len += 1;
+    }
+
+    fn remove(&mut self, byte: &u8) {
+        self.counts[*byte as usize] -= 1;
+        self.len -= 1;
+    }
+
+    fn value(&self) -> [usize; 256] {
+        self.counts
+    }
+}
+
+/// `CharProfile` is a [WindowAccumulator] counting every distinct char
+/// in a window, see [Gradient::frequency_profiles].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CharProfile {
+    counts: BTreeMap<char, usize>,
+    len: usize,
+}
+impl CharProfile {
+    /// `count` of `symbol` in the window
+    pub fn count(&self, symbol: char) -> usize {
+        self.counts.get(&symbol).copied().unwrap_or(0)
+    }
+
+    /// `counts` of the chars in the window, in ascending order
+    pub fn counts(&self) -> impl Iterator<Item = (char, usize)> + '_ {
+        self.counts.iter().map(|(symbol, count)| (*symbol, *count))
+    }
+
+    /// `frequency` of `symbol` in the window, from `0.0` to `1.0`
+    pub fn frequency(&self, symbol: char) -> f64 {
+        frequency(self.count(symbol), self.len)
+    }
+
+    /// `len` of the window
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+impl WindowAccumulator<char> for CharProfile {
+    type Output = BTreeMap<char, usize>;
+
+    fn add(&mut self, symbol: &char) {
+        *self.counts.entry(*symbol).or_default() += 1;
+        self.len += 1;
+    }
+
+    fn remove(&mut self, symbol: &char) {
+        if let Some(count) = self.counts.get_mut(symbol) {
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(symbol);
+            }
+            self.len -= 1;
+        }
+    }
+
+    fn value(&self) -> BTreeMap<char, usize> {
+        self.counts.clone()
+    }
+}
+
+fn frequency(count: usize, len: usize) -> f64 {
+    if len == 0 {
+        return 0.0;
+    }
+    count as f64 / len as f64
+}
+
+/// `FrequencyProfiles` lends every window of a [Gradient] along with
+/// the histogram of its symbols, updated in place as the window
+/// slides, see [Gradient::frequency_profiles].
+///
+/// Histograms are too large to copy per window, so, like
+/// [Frames](crate::Frames), the profile is lent out one window at a
+/// time via [next_profile](Self::next_profile).
+#[derive(Clone, Debug)]
+pub struct FrequencyProfiles<'a, G, P, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    profile: P,
+    range: Range<usize>,
+}
+impl<'a, G: 'a, P: WindowAccumulator<G> + Default, S: Sliceable<G>> FrequencyProfiles<'a, G, P, S> {
+    /// `next_profile` returns the next window along with its profile,
+    /// or `None` once every window has been profiled.
+    pub fn next_profile(&mut self) -> Option<(&'a [G], &P)> {
+        let window = self.gradient.next()?;
+        let range = self.gradient.range();
+        let input = self.gradient.input.as_slice();
+        if range.len() == self.range.len() && range.start == self.range.start + 1 {
+            self.profile.remove(&input[self.range.start]);
+            self.profile.add(&input[range.end - 1]);
+        } else {
+            self.profile = P::default();
+            window.iter().for_each(|symbol| self.profile.add(symbol));
+        }
+        self.range = range;
+        Some((window, &self.profile))
+    }
+
+    /// `range` of the window most recently returned by
+    /// [next_profile](Self::next_profile).
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+impl<'a, S: Sliceable<u8>> Gradient<'a, u8, S> {
+    /// `frequency_profiles` lends every window along with a
+    /// [ByteProfile] of it; sliding a window updates two counts
+    /// rather than recounting it.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut profiles = Gradient::with_max_width(b"GATTACA".to_vec(), Some(4)).frequency_profiles();
+    /// let mut gc = Vec::new();
+    /// while let Some((window, profile)) = profiles.next_profile() {
+    ///     if window.len() == 4 {
+    ///         gc.push(profile.frequency(b'G') + profile.frequency(b'C'));
+    ///     }
+    /// }
+    /// assert_eq!(gc, vec![0.25, 0.0, 0.25, 0.25]);
+    /// ```
+    pub fn frequency_profiles(self) -> FrequencyProfiles<'a, u8, ByteProfile, S> {
+        FrequencyProfiles {
+            gradient: self,
+            profile: ByteProfile::default(),
+            range: 0..0,
+        }
+    }
+}
+
+impl<'a, S: Sliceable<char>> Gradient<'a, char, S> {
+    /// `frequency_profiles` lends every window along with a
+    /// [CharProfile] of it; sliding a window updates two counts
+    /// rather than recounting it.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut profiles = Gradient::new("a1b2".chars().collect()).frequency_profiles();
+    /// let mut digits = Vec::new();
+    /// while let Some((window, profile)) = profiles.next_profile() {
+    ///     if window.len() == 3 {
+    ///         digits.push(profile.counts().filter(|(symbol, _)| symbol.is_ascii_digit()).count());
+    ///     }
+    /// }
+    /// assert_eq!(digits, vec![1, 2]);
+    /// ```
+    pub fn frequency_profiles(self) -> FrequencyProfiles<'a, char, CharProfile, S> {
+        FrequencyProfiles {
+            gradient: self,
+            profile: CharProfile::default(),
+            range: 0..0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_profiles_match_counting_each_window() {
+        let input = b"\x00\xffabracadabra\x00".to_vec();
+        let mut profiles = Gradient::from_slice(&input).frequency_profiles();
+        let mut windows = 0;
+        while let Some((window, profile)) = profiles.next_profile() {
+            windows += 1;
+            assert_eq!(profile.len(), window.len());
+            for byte in 0..=255u8 {
+                let count = window.iter().filter(|symbol| **symbol == byte).count();
+                assert_eq!(profile.count(byte), count);
+            }
+        }
+        assert_eq!(windows, Gradient::from_slice(&input).count());
+    }
+
+    #[test]
+    fn char_profiles_forget_symbols_that_left() {
+        let mut profiles =
+            Gradient::with_max_width("aab".chars().collect(), Some(2)).frequency_profiles();
+        let mut seen = Vec::new();
+        while let Some((_, profile)) = profiles.next_profile() {
+            seen.push(profile.counts().collect::<Vec<_>>());
+        }
+        assert_eq!(
+            seen,
+            vec![
+                vec![('a', 1)],
+                vec![('a', 1)],
+                vec![('b', 1)],
+                vec![('a', 2)],
+                vec![('a', 1), ('b', 1)]
+            ]
+        );
+    }
+}
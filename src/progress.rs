@@ -0,0 +1,136 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+
+/// `Progress` is handed to the callback of [OnProgress] describing
+/// how far the underlying [Gradient] has gone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Progress {
+    /// number of windows yielded so far
+    pub yielded: usize,
+    /// number of windows the gradient yields in total
+    pub total: usize,
+    /// width of the most recently yielded window
+    pub width: usize,
+}
+
+/// `OnProgress` is an iterator adaptor that invokes a callback with a
+/// [Progress] every `every_n` windows, see [Gradient::on_progress].
+#[derive(Clone)]
+pub struct OnProgress<'a, G, F, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    every_n: usize,
+    callback: F,
+    yielded: usize,
+    total: usize,
+}
+impl<'a, G: 'a, F: FnMut(Progress), S: Sliceable<G>> Iterator for OnProgress<'a, G, F, S> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        let window = self.gradient.next()?;
+        self.yielded += 1;
+        if self.yielded % self.every_n == 0 {
+            (self.callback)(Progress {
+                yielded: self.yielded,
+                total: self.total,
+                width: window.len(),
+            });
+        }
+        Some(window)
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `on_progress` invokes `callback` with a [Progress] every
+    /// `every_n` windows yielded. Windows the gradient already
+    /// yielded before the call count towards `yielded`.
+    ///
+    /// # Panics
+    ///
+    /// When `every_n` is zero.
+    ///
+    /// ```
+    /// use gradient_slice::{Gradient, Progress};
+    /// let mut reports = Vec::new();
+    /// let count = Gradient::new(vec![1, 2, 3])
+    ///     .on_progress(2, |progress| reports.push(progress))
+    ///     .count();
+    /// assert_eq!(count, 6);
+    /// assert_eq!(
+    ///     reports,
+    ///     vec![
+    ///         Progress { yielded: 2, total: 6, width: 1 },
+    ///         Progress { yielded: 4, total: 6, width: 2 },
+    ///         Progress { yielded: 6, total: 6, width: 3 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn on_progress<F: FnMut(Progress)>(
+        self,
+        every_n: usize,
+        callback: F,
+    ) -> OnProgress<'a, G, F, S> {
+        assert!(every_n > 0, "every_n must be greater than zero");
+        OnProgress {
+            yielded: self.yielded(),
+            total: self.total(),
+            gradient: self,
+            every_n,
+            callback,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn on_progress_with_max_width() {
+        let mut last = None;
+        let windows = Gradient::with_max_width(" abc ".chars().collect(), Some(2))
+            .on_progress(1, |progress| last = Some(progress))
+            .count();
+        assert_eq!(windows, 9);
+        assert_eq!(
+            last,
+            Some(Progress {
+                yielded: 9,
+                total: 9,
+                width: 2
+            })
+        );
+    }
+
+    #[test]
+    fn on_progress_after_advancing() {
+        let mut gradient = Gradient::new(vec![1, 2, 3]);
+        gradient.next();
+        let mut reports = Vec::new();
+        let count = gradient
+            .on_progress(1, |progress| reports.push(progress.yielded))
+            .count();
+        assert_eq!(count, 5);
+        assert_eq!(reports, vec![2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn on_progress_seeds_position_at_width_boundaries() {
+        let mut gradient = Gradient::with_max_width(vec![1, 2, 3, 4], Some(2));
+        for _ in 0..4 {
+            gradient.next();
+        }
+        let mut last = None;
+        gradient
+            .on_progress(1, |progress| last = Some(progress))
+            .for_each(drop);
+        assert_eq!(
+            last,
+            Some(Progress {
+                yielded: 7,
+                total: 7,
+                width: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::iter::Map;
+use core::slice::Iter;
+
+/// `Projected` yields the elements of every window of a [Gradient]
+/// projected through a function, see [Gradient::with_projection].
+#[derive(Clone, Debug)]
+pub struct Projected<'a, G, F, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    f: F,
+}
+impl<'a, G: 'a, T, F: Fn(&'a G) -> T + Clone, S: Sliceable<G>> Iterator for Projected<'a, G, F, S> {
+    type Item = Map<Iter<'a, G>, F>;
+
+    fn next(&mut self) -> Option<Map<Iter<'a, G>, F>> {
+        Some(self.gradient.next()?.iter().map(self.f.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.gradient.size_hint()
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `with_projection` yields, for every window of the
+    /// [Gradient](Self), an iterator applying `f` to its elements as
+    /// they are consumed, rather than projecting the whole input into
+    /// a second allocation up front.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let words = ["a", "bb", "ccc"];
+    /// let lengths = Gradient::from_slice(&words)
+    ///     .with_projection(|word| word.len())
+    ///     .map(|window| window.sum::<usize>())
+    ///     .collect::<Vec<usize>>();
+    /// assert_eq!(lengths, vec![1, 2, 3, 3, 5, 6]);
+    /// ```
+    pub fn with_projection<T, F: Fn(&'a G) -> T + Clone>(self, f: F) -> Projected<'a, G, F, S> {
+        Projected { gradient: self, f }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn projection_matches_mapping_the_input() {
+        let input = [1u8, 200, 3, 40];
+        let projected = input
+            .iter()
+            .map(|byte| u32::from(*byte) * 3)
+            .collect::<Vec<_>>();
+        for max_width in [None, Some(2)] {
+            let gradient = Gradient {
+                max_width,
+                ..Gradient::from_slice(&input)
+            };
+            let windows = gradient
+                .with_projection(|byte| u32::from(*byte) * 3)
+                .map(Iterator::collect::<Vec<_>>)
+                .collect::<Vec<_>>();
+            let expected = Gradient {
+                max_width,
+                ..Gradient::from_slice(&projected)
+            };
+            assert_eq!(windows, expected.map(<[u32]>::to_vec).collect::<Vec<_>>());
+        }
+    }
+}
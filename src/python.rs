@@ -0,0 +1,136 @@
+use crate::Gradient;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyMemoryView, PySlice, PyString};
+
+/// the Python object windows are sliced from
+enum Input {
+    /// any object exporting a buffer, such as `bytes`
+    Buffer(Py<PyMemoryView>),
+    Str(Py<PyString>),
+    List(Py<PyList>),
+}
+
+/// `GradientPy` exposes a [Gradient] to Python, through [pyo3], as an
+/// iterator class named `Gradient`.
+///
+/// It accepts `bytes`, or any other object exporting a buffer, `str`
+/// and `list`, and yields the windows of the same type sliced from the
+/// input: `memoryview`s over the original buffer, without copying, for
+/// bytes, strings of `str` characters and lists for lists.
+///
+/// ```python
+/// from gradient_slice import Gradient
+/// windows = Gradient("abc", max_width=2)
+/// assert list(windows) == ["a", "b", "c", "ab", "bc"]
+/// ```
+#[pyclass(name = "Gradient", module = "gradient_slice")]
+pub struct GradientPy {
+    input: Input,
+    /// traverses the positions of the input's elements
+    positions: Gradient<'static, ()>,
+}
+
+#[pymethods]
+impl GradientPy {
+    #[new]
+    #[pyo3(signature = (object, max_width = None))]
+    fn new(object: &Bound<'_, PyAny>, max_width: Option<usize>) -> PyResult<GradientPy> {
+        let input = if let Ok(string) = object.downcast::<PyString>() {
+            Input::Str(string.clone().unbind())
+        } else if let Ok(list) = object.downcast::<PyList>() {
+            Input::List(list.clone().unbind())
+        } else if let Ok(view) = PyMemoryView::from(object) {
+            Input::Buffer(view.unbind())
+        } else {
+            return Err(PyTypeError::new_err("expected bytes, str or list"));
+        };
+        let len = object.len()?;
+        Ok(GradientPy {
+            input,
+            positions: Gradient::with_max_width(vec![(); len], max_width),
+        })
+    }
+
+    fn __iter__(this: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        this
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        if self.positions.next().is_none() {
+            return Ok(None);
+        }
+        let range = self.positions.range();
+        let slice = PySlice::new(py, range.start as isize, range.end as isize, 1);
+        let window = match &self.input {
+            Input::Buffer(view) => view.bind(py).get_item(slice)?,
+            Input::Str(string) => string.bind(py).get_item(slice)?,
+            Input::List(list) => list.bind(py).as_any().get_item(slice)?,
+        };
+        Ok(Some(window.unbind()))
+    }
+
+    /// `start` is the offset of the current window's first element.
+    #[getter]
+    fn start(&self) -> usize {
+        self.positions.start()
+    }
+
+    /// `end` is the offset past the current window's last element.
+    #[getter]
+    fn end(&self) -> usize {
+        self.positions.end()
+    }
+
+    /// `total` number of windows from start to finish.
+    fn total(&self) -> usize {
+        self.positions.total()
+    }
+}
+
+/// `gradient_slice` registers [GradientPy] in a Python extension
+/// module of that name.
+#[pymodule]
+pub fn gradient_slice(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<GradientPy>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn gradient_py_slices_like_python() {
+        Python::initialize();
+        Python::attach(|py| {
+            let globals = PyDict::new(py);
+            globals
+                .set_item("Gradient", py.get_type::<GradientPy>())
+                .unwrap();
+            py.run(
+                cr#"
+data = b"abc"
+windows = Gradient(data)
+views = list(windows)
+assert all(isinstance(view, memoryview) and view.obj is data for view in views), views
+assert [bytes(view) for view in views] == [b"a", b"b", b"c", b"ab", b"bc", b"abc"]
+assert list(Gradient("héllo", max_width=1)) == ["h", "é", "l", "l", "o"]
+assert list(Gradient([1, 2, 3], 2)) == [[1], [2], [3], [1, 2], [2, 3]]
+windows = Gradient("ab")
+next(windows)
+next(windows)
+assert (windows.start, windows.end, windows.total()) == (1, 2, 3)
+try:
+    Gradient(42)
+    raise AssertionError("expected TypeError")
+except TypeError:
+    pass
+"#,
+                Some(&globals),
+                None,
+            )
+            .unwrap();
+        });
+    }
+}
@@ -0,0 +1,57 @@
+use crate::Gradient;
+use csv::{Reader, StringRecord};
+use std::io::Read;
+
+impl<'a> Gradient<'a, StringRecord> {
+    /// `from_csv` reads every remaining record of `reader` and creates
+    /// a [Gradient](Self) over them, yielding windows of consecutive
+    /// records as `&[StringRecord]`.
+    ///
+    /// Delimiters, quoting and headers are whatever `reader` was built
+    /// with, e.g. tabs for TSV. Fails on the first record that doesn't
+    /// parse.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut reader = csv::ReaderBuilder::new()
+    ///     .delimiter(b'\t')
+    ///     .from_reader("id\tname\n1\tada\n2\tgrace\n".as_bytes());
+    /// let gradient = Gradient::from_csv(&mut reader, Some(2)).unwrap();
+    /// let spans = gradient
+    ///     .map(|records| records.iter().map(|record| record[1].to_string()).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(spans, vec![vec!["ada"], vec!["grace"], vec!["ada", "grace"]]);
+    /// ```
+    pub fn from_csv<R: Read>(
+        reader: &mut Reader<R>,
+        max_width: Option<usize>,
+    ) -> csv::Result<Gradient<'a, StringRecord>> {
+        let records = reader.records().collect::<csv::Result<Vec<_>>>()?;
+        Ok(Gradient::with_max_width(records, max_width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_csv_yields_record_spans() {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader("a,1\nb,2\n\"c,\",3\n".as_bytes());
+        let gradient = Gradient::from_csv(&mut reader, None).unwrap();
+        assert_eq!(gradient.total(), 6);
+        let widest = gradient.map(<[StringRecord]>::to_vec).last().unwrap();
+        assert_eq!(widest.len(), 3);
+        assert_eq!(&widest[2][0], "c,");
+    }
+
+    #[test]
+    fn from_csv_reports_malformed_records() {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader("a,1\nb\n".as_bytes());
+        assert!(Gradient::from_csv(&mut reader, None).is_err());
+    }
+}
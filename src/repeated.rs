@@ -0,0 +1,105 @@
+use crate::rolling::{element_hashes, width_hashes};
+use crate::{Gradient, Sliceable};
+use core::hash::Hash;
+use std::collections::HashMap;
+
+impl<'a, G: Hash + Eq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `longest_repeated_window` returns the widest window whose
+    /// contents occur at two or more positions, along with the start
+    /// of every occurrence. Among equally wide repeats, the one
+    /// occurring first wins.
+    ///
+    /// A window repeating implies its narrower sub-windows repeat, so
+    /// widths are binary searched, each one in `O(n)` rolling hashes
+    /// confirmed by comparing contents.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"banana".to_vec());
+    /// assert_eq!(
+    ///     gradient.longest_repeated_window(),
+    ///     Some((&b"ana"[..], vec![1, 3]))
+    /// );
+    /// assert_eq!(Gradient::new(b"abc".to_vec()).longest_repeated_window(), None);
+    /// ```
+    pub fn longest_repeated_window(&self) -> Option<(&[G], Vec<usize>)> {
+        let input = self.input.as_slice();
+        let elements = element_hashes(input);
+        let (mut found, mut narrowest_failing) = (None, self.widest() + 1);
+        let mut widest_passing = 0;
+        while widest_passing + 1 < narrowest_failing {
+            let width = widest_passing + (narrowest_failing - widest_passing) / 2;
+            match first_repeat(input, &elements, width) {
+                Some(starts) => {
+                    widest_passing = width;
+                    found = Some((&input[starts[0]..starts[0] + width], starts));
+                }
+                None => narrowest_failing = width,
+            }
+        }
+        found
+    }
+}
+
+/// starts of the repeated window of `width` whose first occurrence is
+/// leftmost, if any window of `width` repeats
+fn first_repeat<G: Eq>(input: &[G], elements: &[u64], width: usize) -> Option<Vec<usize>> {
+    let mut buckets: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (start, hash) in width_hashes(elements, width).enumerate() {
+        buckets.entry(hash).or_default().push(start);
+    }
+    let window = |start: usize| &input[start..start + width];
+    let mut first: Option<Vec<usize>> = None;
+    for mut starts in buckets.into_values().filter(|starts| starts.len() > 1) {
+        while let Some(&leader) = starts.first() {
+            let (same, other) = starts
+                .iter()
+                .partition::<Vec<usize>, _>(|start| window(**start) == window(leader));
+            if same.len() > 1 && first.as_ref().is_none_or(|first| leader < first[0]) {
+                first = Some(same);
+            }
+            starts = other;
+        }
+    }
+    first
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_repeated_window_agrees_with_naive_search() {
+        for input in ["mississippi", "abcabcabc", "aaaa", "ab", "", "xyzzyx yzzy"] {
+            let input = input.as_bytes();
+            let gradient = Gradient::from_slice(input);
+            let mut expected = None;
+            for width in (1..input.len()).rev() {
+                let starts = (0..=input.len() - width).collect::<Vec<_>>();
+                let repeat = starts.iter().find_map(|first| {
+                    let window = &input[*first..first + width];
+                    let all = starts
+                        .iter()
+                        .copied()
+                        .filter(|start| input[*start..start + width] == *window)
+                        .collect::<Vec<_>>();
+                    (all.len() > 1).then_some((window, all))
+                });
+                if repeat.is_some() {
+                    expected = repeat;
+                    break;
+                }
+            }
+            assert_eq!(gradient.longest_repeated_window(), expected);
+        }
+    }
+
+    #[test]
+    fn longest_repeated_window_respects_max_width() {
+        let gradient = Gradient::with_max_width(b"abcdabcd".to_vec(), Some(2));
+        assert_eq!(
+            gradient.longest_repeated_window(),
+            Some((&b"ab"[..], vec![0, 4]))
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Polynomial rolling hashes modulo the Mersenne prime `2^61 - 1`,
+//! over per-element hashes so any `G: Hash` can be rolled.
+
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+pub(crate) const MODULUS: u64 = (1 << 61) - 1;
+const BASE: u64 = 0x0A3B_95C7_1D2E_4F61 % MODULUS;
+
+pub(crate) fn add(left: u64, right: u64) -> u64 {
+    let sum = left + right;
+    if sum >= MODULUS { sum - MODULUS } else { sum }
+}
+
+pub(crate) fn sub(left: u64, right: u64) -> u64 {
+    add(left, MODULUS - right)
+}
+
+pub(crate) fn mul(left: u64, right: u64) -> u64 {
+    let product = left as u128 * right as u128;
+    let folded = (product & MODULUS as u128) as u64 + (product >> 61) as u64;
+    if folded >= MODULUS {
+        folded - MODULUS
+    } else {
+        folded
+    }
+}
+
+/// 64-bit FNV-1a, a deterministic hasher that works without `std`
+struct Fnv(u64);
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x0100_0000_01B3);
+        }
+    }
+}
+
+/// splitmix64, a deterministic mixer deriving well-spread values
+/// from consecutive seeds
+pub(crate) fn splitmix(mut state: u64) -> u64 {
+    state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    state ^ (state >> 31)
+}
+
+/// hash of a single element, reduced below [MODULUS]
+pub(crate) fn element_hash<G: Hash>(element: &G) -> u64 {
+    let mut hasher = Fnv(0xCBF2_9CE4_8422_2325);
+    element.hash(&mut hasher);
+    hasher.finish() % MODULUS
+}
+
+pub(crate) fn element_hashes<G: Hash>(input: &[G]) -> Vec<u64> {
+    input.iter().map(element_hash).collect()
+}
+
+/// rolling hash of the windows of one width, updated in `O(1)` as the
+/// window slides one element to the right
+#[derive(Clone, Debug)]
+pub(crate) struct Rolling {
+    hash: u64,
+    /// `BASE^(width - 1)`, the weight of the element leaving the window
+    leading: u64,
+}
+impl Rolling {
+    /// hash of `window`, the first window of its width
+    pub(crate) fn new(window: &[u64]) -> Rolling {
+        let mut rolling = Rolling {
+            hash: 0,
+            leading: 1,
+        };
+        for (index, element) in window.iter().enumerate() {
+            if index > 0 {
+                rolling.leading = mul(rolling.leading, BASE);
+            }
+            rolling.hash = add(mul(rolling.hash, BASE), *element);
+        }
+        rolling
+    }
+
+    pub(crate) fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// slides the window, dropping `leaving` and appending `entering`
+    pub(crate) fn roll(&mut self, leaving: u64, entering: u64) {
+        let kept = sub(self.hash, mul(leaving, self.leading));
+        self.hash = add(mul(kept, BASE), entering);
+    }
+}
+
+/// rolling hashes of every window of `width` over `elements`
+pub(crate) fn width_hashes(elements: &[u64], width: usize) -> impl Iterator<Item = u64> + '_ {
+    let mut rolling = (width <= elements.len()).then(|| Rolling::new(&elements[..width]));
+    (0..(elements.len() + 1).saturating_sub(width)).map(move |start| {
+        let rolling = rolling.as_mut().expect("windows exist");
+        if start > 0 {
+            rolling.roll(elements[start - 1], elements[start + width - 1]);
+        }
+        rolling.hash()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolled_hashes_match_hashing_from_scratch() {
+        let elements = element_hashes(b"the quick brown fox");
+        for width in 1..=elements.len() {
+            let rolled = width_hashes(&elements, width).collect::<Vec<_>>();
+            let scratch = elements
+                .windows(width)
+                .map(|window| Rolling::new(window).hash())
+                .collect::<Vec<_>>();
+            assert_eq!(rolled, scratch);
+        }
+        assert_eq!(width_hashes(&elements, elements.len() + 1).count(), 0);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(add(MODULUS - 1, 2), 1);
+        assert_eq!(sub(1, 2), MODULUS - 1);
+        assert_eq!(mul(MODULUS - 1, MODULUS - 1), 1);
+    }
+}
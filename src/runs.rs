@@ -0,0 +1,217 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// `RunWindow` is a logical window of run-length-encoded input: the
+/// runs it touches, with the first and last ones trimmed to the
+/// window, see [RunGradient].
+#[derive(Debug)]
+pub struct RunWindow<'r, G> {
+    runs: &'r [(G, usize)],
+    /// elements of the first run before the window
+    skip: usize,
+    len: usize,
+}
+impl<G> Clone for RunWindow<'_, G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<G> Copy for RunWindow<'_, G> {}
+impl<'r, G> RunWindow<'r, G> {
+    /// `len` of the window in elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `runs` of the window as `(value, count)` pairs, counts trimmed
+    /// to the elements within the window
+    pub fn runs(&self) -> impl Iterator<Item = (&'r G, usize)> + 'r {
+        let (mut skip, mut remaining) = (self.skip, self.len);
+        self.runs.iter().filter_map(move |(value, count)| {
+            let skipped = skip.min(*count);
+            let count = (count - skipped).min(remaining);
+            skip -= skipped;
+            remaining -= count;
+            (count > 0).then_some((value, count))
+        })
+    }
+
+    /// `iter` over the elements of the window, decoding them one at a
+    /// time
+    pub fn iter(&self) -> impl Iterator<Item = &'r G> + 'r {
+        self.runs()
+            .flat_map(|(value, count)| core::iter::repeat_n(value, count))
+    }
+
+    /// `to_vec` decodes the window
+    pub fn to_vec(&self) -> Vec<G>
+    where
+        G: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+impl<G: PartialEq> PartialEq<[G]> for RunWindow<'_, G> {
+    fn eq(&self, other: &[G]) -> bool {
+        self.len == other.len() && self.iter().eq(other)
+    }
+}
+
+/// `RunGradient` yields the windows of run-length-encoded input, given
+/// as `(value, count)` runs, in the order of a
+/// [Gradient](crate::Gradient) over the decoded elements, without ever
+/// decoding the input.
+///
+/// Each [RunWindow] borrows the runs it touches, located from the
+/// previous window's, so memory stays proportional to the number of
+/// runs rather than of elements.
+///
+/// ```
+/// use gradient_slice::RunGradient;
+/// let runs = [('a', 3), ('b', 1)];
+/// let windows = RunGradient::with_max_width(&runs, Some(2))
+///     .map(|window| window.iter().collect::<String>())
+///     .collect::<Vec<_>>();
+/// assert_eq!(windows, vec!["a", "a", "a", "b", "aa", "aa", "ab"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RunGradient<'r, G> {
+    runs: &'r [(G, usize)],
+    /// `ends[i]`: number of elements in `runs[..=i]`
+    ends: Vec<usize>,
+    max_width: Option<usize>,
+    width: usize,
+    start: usize,
+    /// first run ending after `start`
+    first: usize,
+    /// first run ending at or after `start + width`
+    last: usize,
+    range: Range<usize>,
+}
+impl<'r, G> RunGradient<'r, G> {
+    pub fn new(runs: &'r [(G, usize)]) -> RunGradient<'r, G> {
+        RunGradient::with_max_width(runs, None)
+    }
+
+    /// `with_max_width` creates a [RunGradient](Self) that optionally
+    /// spans to a maximum window width in decoded elements.
+    pub fn with_max_width(runs: &'r [(G, usize)], max_width: Option<usize>) -> RunGradient<'r, G> {
+        let ends = runs
+            .iter()
+            .scan(0usize, |end, (_, count)| {
+                *end += count;
+                Some(*end)
+            })
+            .collect::<Vec<_>>();
+        RunGradient {
+            runs,
+            ends,
+            max_width,
+            width: 1,
+            start: 0,
+            first: 0,
+            last: 0,
+            range: 0..0,
+        }
+    }
+
+    /// `len` of the decoded input
+    pub fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `range` of the decoded elements in the window most recently
+    /// yielded
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+impl<'r, G> Iterator for RunGradient<'r, G> {
+    type Item = RunWindow<'r, G>;
+
+    fn next(&mut self) -> Option<RunWindow<'r, G>> {
+        let len = self.len();
+        let widest = match self.max_width {
+            Some(max_width) => max_width.min(len),
+            None => len,
+        };
+        if self.width > widest {
+            return None;
+        }
+        let (start, end) = (self.start, self.start + self.width);
+        if start == 0 {
+            self.first = 0;
+            self.last = 0;
+        }
+        while self.ends[self.first] <= start {
+            self.first += 1;
+        }
+        while self.ends[self.last] < end {
+            self.last += 1;
+        }
+        let before = self.ends[self.first] - self.runs[self.first].1;
+        self.range = start..end;
+        self.start += 1;
+        if self.start + self.width > len {
+            self.width += 1;
+            self.start = 0;
+        }
+        Some(RunWindow {
+            runs: &self.runs[self.first..=self.last],
+            skip: start - before,
+            len: end - start,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gradient;
+
+    #[test]
+    fn run_gradient_matches_decoded_gradient() {
+        let runs = [(7u8, 3), (0, 0), (1, 1), (2, 4), (7, 0), (3, 2)];
+        let decoded = runs
+            .iter()
+            .flat_map(|(value, count)| core::iter::repeat_n(*value, *count))
+            .collect::<Vec<_>>();
+        for max_width in [None, Some(3)] {
+            let mut expected = Gradient::with_max_width(decoded.clone(), max_width);
+            let mut windows = RunGradient::with_max_width(&runs, max_width);
+            while let Some(window) = windows.next() {
+                let next = expected.next().map(<[u8]>::to_vec);
+                assert_eq!(Some(window.to_vec()), next);
+                assert_eq!(windows.range(), expected.range());
+                assert!(window.runs().all(|(_, count)| count > 0));
+                assert!(window == expected.input.as_slice()[windows.range()]);
+            }
+            assert_eq!(expected.next(), None);
+        }
+    }
+
+    #[test]
+    fn run_window_runs_are_trimmed() {
+        let runs = [('x', 10), ('y', 5)];
+        let mut windows = RunGradient::with_max_width(&runs, Some(4)).skip(15 + 14 + 8);
+        let window = windows.next().unwrap();
+        assert_eq!(
+            window.runs().collect::<Vec<_>>(),
+            vec![(&'x', 2), (&'y', 1)]
+        );
+        assert_eq!(
+            RunGradient::<u8>::new(&[])
+                .next()
+                .map(|window| window.len()),
+            None
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `dictionary_samples` selects windows of the given `widths`
+    /// totalling at most `max_bytes`, as training samples for
+    /// compression dictionary builders such as `zstd --train`.
+    ///
+    /// The budget is split evenly between widths, and the windows of
+    /// each width are centered in equally sized strata of the input,
+    /// so samples cover every region of the input at every width.
+    /// Widths wider than the widest window are ignored, and samples
+    /// are ordered by width, then by position.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new((0..=99u8).collect::<Vec<_>>());
+    /// let samples = gradient.dictionary_samples(&[4, 8], 32);
+    /// let starts = samples.iter().map(|sample| sample[0]).collect::<Vec<u8>>();
+    /// assert_eq!(starts, vec![12, 36, 60, 84, 23, 69]);
+    /// assert_eq!(samples.iter().map(|sample| sample.len()).sum::<usize>(), 32);
+    /// ```
+    pub fn dictionary_samples(&self, widths: &[usize], max_bytes: usize) -> Vec<&[G]> {
+        let input = self.input.as_slice();
+        let mut widths = widths
+            .iter()
+            .copied()
+            .filter(|width| (1..=self.widest()).contains(width))
+            .collect::<Vec<_>>();
+        widths.sort_unstable();
+        widths.dedup();
+        let mut samples = Vec::new();
+        if widths.is_empty() {
+            return samples;
+        }
+        let budget = max_bytes / widths.len();
+        for width in widths {
+            let positions = input.len() - width + 1;
+            let bytes = (width * core::mem::size_of::<G>()).max(1);
+            let count = (budget / bytes).min(positions);
+            samples.extend((0..count).map(|stratum| {
+                let start = (2 * stratum + 1) * positions / (2 * count);
+                &input[start..start + width]
+            }));
+        }
+        samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dictionary_samples_stay_within_budget_and_cover_input() {
+        let input = (0..1000u32).collect::<Vec<_>>();
+        let gradient = Gradient::with_max_width(input.clone(), Some(64));
+        let samples = gradient.dictionary_samples(&[16, 4, 64, 256, 16], 4096);
+        let bytes = samples
+            .iter()
+            .map(|sample| core::mem::size_of_val(*sample))
+            .sum::<usize>();
+        assert!(bytes <= 4096, "{}", bytes);
+        for width in [4, 16, 64] {
+            let starts = samples
+                .iter()
+                .filter(|sample| sample.len() == width)
+                .map(|sample| sample[0])
+                .collect::<Vec<_>>();
+            assert!(starts.windows(2).all(|pair| pair[0] < pair[1]));
+            let end = *starts.last().unwrap() as usize + width;
+            assert!(starts[0] < 100 && end > 900, "{:?}", starts);
+        }
+        assert!(samples.iter().all(|sample| sample.len() != 256));
+    }
+
+    #[test]
+    fn dictionary_samples_never_exceed_positions() {
+        let gradient = Gradient::new(b"abc".to_vec());
+        let samples = gradient.dictionary_samples(&[2, 0], 1 << 20);
+        assert_eq!(samples, vec![&b"ab"[..], b"bc"]);
+        assert!(gradient.dictionary_samples(&[], 10).is_empty());
+    }
+}
@@ -0,0 +1,346 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+impl<'a, G: PartialEq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `find_window` returns the range of the first window equal to
+    /// `needle`, only scanning windows as wide as the needle rather
+    /// than every narrower width the iterator would visit first.
+    ///
+    /// Returns `None` for an empty needle or one wider than the
+    /// widest window, as the [Gradient](Self) never yields those.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abracadabra".to_vec());
+    /// assert_eq!(gradient.find_window(b"cad"), Some(4..7));
+    /// assert_eq!(gradient.find_window(b"dab"), Some(6..9));
+    /// assert_eq!(gradient.find_window(b"bad"), None);
+    /// ```
+    pub fn find_window(&self, needle: &[G]) -> Option<Range<usize>> {
+        if needle.is_empty() || needle.len() > self.widest() {
+            return None;
+        }
+        let start = self
+            .input
+            .as_slice()
+            .windows(needle.len())
+            .position(|window| window == needle)?;
+        Some(start..start + needle.len())
+    }
+
+    /// `contains` tells whether `needle` is a contiguous sub-slice of
+    /// the input, in `O(n + m)` comparisons using Knuth-Morris-Pratt
+    /// instead of enumerating windows. Unlike
+    /// [find_window](Self::find_window) it ignores `max_width`, and an
+    /// empty needle is always contained.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::with_max_width(b"mississippi".to_vec(), Some(2));
+    /// assert!(gradient.contains(b"issip"));
+    /// assert!(!gradient.contains(b"sipi"));
+    /// ```
+    pub fn contains(&self, needle: &[G]) -> bool {
+        if needle.is_empty() {
+            return true;
+        }
+        self.find_all(needle).next().is_some()
+    }
+
+    /// `find_all` iterates over the range of every occurrence of
+    /// `needle` in the input, including overlapping ones, in
+    /// ascending order. Like [contains](Self::contains) it ignores
+    /// `max_width`; an empty needle yields nothing.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abababa".to_vec());
+    /// let ranges = gradient.find_all(b"aba").collect::<Vec<_>>();
+    /// assert_eq!(ranges, vec![0..3, 2..5, 4..7]);
+    /// ```
+    pub fn find_all<'s, 'n>(&'s self, needle: &'n [G]) -> FindAll<'s, 'n, G> {
+        FindAll {
+            haystack: self.input.as_slice(),
+            needle,
+            failure: if needle.is_empty() {
+                Vec::new()
+            } else {
+                failure(needle)
+            },
+            position: 0,
+            matched: 0,
+        }
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `longest_window_where` returns the range of the widest window
+    /// for which `predicate` holds, the leftmost one among equally
+    /// wide windows.
+    ///
+    /// The predicate must be monotone in width: if a window fails,
+    /// every wider window containing it fails too. Widths are then
+    /// binary searched, calling `predicate` `O(n log n)` times rather
+    /// than once per window.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let sizes = Gradient::new(vec![4, 1, 2, 7, 1, 1, 3]);
+    /// let longest = sizes.longest_window_where(|window| window.iter().sum::<i32>() <= 6);
+    /// assert_eq!(longest, Some(4..7));
+    /// ```
+    pub fn longest_window_where<F: FnMut(&[G]) -> bool>(
+        &self,
+        mut predicate: F,
+    ) -> Option<Range<usize>> {
+        let input = self.input.as_slice();
+        let mut first = |width: usize| {
+            input
+                .windows(width)
+                .position(&mut predicate)
+                .map(|start| start..start + width)
+        };
+        let (mut found, mut narrowest_failing) = (None, self.widest() + 1);
+        let mut widest_passing = 0;
+        while widest_passing + 1 < narrowest_failing {
+            let width = widest_passing + (narrowest_failing - widest_passing) / 2;
+            match first(width) {
+                Some(range) => {
+                    widest_passing = width;
+                    found = Some(range);
+                }
+                None => narrowest_failing = width,
+            }
+        }
+        found
+    }
+
+    /// `shortest_window_where` returns the range of the first window,
+    /// in gradient order, for which `predicate` holds: the leftmost of
+    /// the narrowest such windows.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(vec![1, 5, 2, 6, 3]);
+    /// let shortest = gradient.shortest_window_where(|window| window.iter().sum::<i32>() >= 8);
+    /// assert_eq!(shortest, Some(2..4));
+    /// ```
+    pub fn shortest_window_where<F: FnMut(&[G]) -> bool>(
+        &self,
+        mut predicate: F,
+    ) -> Option<Range<usize>> {
+        let input = self.input.as_slice();
+        (1..=self.widest()).find_map(|width| {
+            input
+                .windows(width)
+                .position(&mut predicate)
+                .map(|start| start..start + width)
+        })
+    }
+
+    /// `shortest_window_where_monotone` returns the same window as
+    /// [shortest_window_where](Self::shortest_window_where) with two
+    /// pointers, calling `predicate` at most `2n` times, for
+    /// predicates that are monotone in growth: if a window holds,
+    /// every wider window containing it holds too, as in the classic
+    /// minimum window substring problem.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let text = Gradient::new(b"adobecodebanc".to_vec());
+    /// let covers = |window: &[u8]| b"abc".iter().all(|wanted| window.contains(wanted));
+    /// assert_eq!(text.shortest_window_where_monotone(covers), Some(9..13));
+    /// ```
+    pub fn shortest_window_where_monotone<F: FnMut(&[G]) -> bool>(
+        &self,
+        mut predicate: F,
+    ) -> Option<Range<usize>> {
+        let input = self.input.as_slice();
+        let mut shortest: Option<Range<usize>> = None;
+        let mut start = 0;
+        for end in 1..=input.len() {
+            while start < end && predicate(&input[start..end]) {
+                if shortest
+                    .as_ref()
+                    .is_none_or(|range| end - start < range.len())
+                {
+                    shortest = Some(start..end);
+                }
+                start += 1;
+            }
+        }
+        shortest.filter(|range| range.len() <= self.widest())
+    }
+}
+
+/// `FindAll` yields the range of every occurrence of a needle, see
+/// [Gradient::find_all].
+#[derive(Clone, Debug)]
+pub struct FindAll<'s, 'n, G> {
+    haystack: &'s [G],
+    needle: &'n [G],
+    failure: Vec<usize>,
+    position: usize,
+    matched: usize,
+}
+impl<G: PartialEq> Iterator for FindAll<'_, '_, G> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        if self.needle.is_empty() {
+            return None;
+        }
+        while let Some(element) = self.haystack.get(self.position) {
+            self.position += 1;
+            while self.matched > 0 && self.needle[self.matched] != *element {
+                self.matched = self.failure[self.matched - 1];
+            }
+            if self.needle[self.matched] == *element {
+                self.matched += 1;
+            }
+            if self.matched == self.needle.len() {
+                self.matched = self.failure[self.matched - 1];
+                return Some(self.position - self.needle.len()..self.position);
+            }
+        }
+        None
+    }
+}
+
+/// Knuth-Morris-Pratt failure table: `failure[i]` is the length of the
+/// longest proper prefix of `needle[..=i]` that is also its suffix.
+fn failure<G: PartialEq>(needle: &[G]) -> Vec<usize> {
+    let mut failure = Vec::with_capacity(needle.len());
+    failure.push(0);
+    let mut prefix = 0;
+    for element in &needle[1..] {
+        while prefix > 0 && needle[prefix] != *element {
+            prefix = failure[prefix - 1];
+        }
+        if needle[prefix] == *element {
+            prefix += 1;
+        }
+        failure.push(prefix);
+    }
+    failure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_window_matches_first_yielded_window() {
+        let input = " abcab ".chars().collect::<Vec<char>>();
+        let gradient = Gradient::from_slice(&input);
+        for needle in ["ab", "b", "cab ", " abcab ", "ba"] {
+            let needle = needle.chars().collect::<Vec<char>>();
+            let mut scan = gradient.clone();
+            let found = scan.find(|window| *window == &needle[..]).is_some();
+            let expected = found.then(|| scan.range());
+            assert_eq!(gradient.find_window(&needle), expected);
+        }
+    }
+
+    #[test]
+    fn contains_agrees_with_naive_search() {
+        let input = b"aabaabaaab".to_vec();
+        let gradient = Gradient::from_slice(&input);
+        for needle in Gradient::new(b"aaabx".to_vec()).by_ref() {
+            let naive = input.windows(needle.len()).any(|window| window == needle);
+            assert_eq!(gradient.contains(needle), naive, "{:?}", needle);
+        }
+        assert!(gradient.contains(&[]));
+        assert!(!Gradient::new(Vec::<u8>::new()).contains(b"a"));
+    }
+
+    #[test]
+    fn find_all_reports_overlapping_occurrences() {
+        let gradient = Gradient::new(b"aaaa".to_vec());
+        let ranges = gradient.find_all(b"aa").collect::<Vec<_>>();
+        assert_eq!(ranges, vec![0..2, 1..3, 2..4]);
+        assert_eq!(gradient.find_all(b"").next(), None);
+        assert_eq!(gradient.find_all(b"aaaaa").next(), None);
+    }
+
+    #[test]
+    fn find_all_agrees_with_gradient_windows() {
+        let input = b"abcabcab".to_vec();
+        let gradient = Gradient::from_slice(&input);
+        let mut scan = gradient.clone();
+        let mut expected = Vec::new();
+        while let Some(window) = scan.next() {
+            if window == b"cab" {
+                expected.push(scan.range());
+            }
+        }
+        assert_eq!(gradient.find_all(b"cab").collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn longest_window_where_agrees_with_scanning_every_window() {
+        let input = vec![3u32, 9, 1, 1, 2, 8, 1, 1, 1, 5];
+        let gradient = Gradient::from_slice(&input);
+        for budget in 0..40 {
+            let fits = |window: &[u32]| window.iter().sum::<u32>() <= budget;
+            let mut scan = gradient.clone();
+            let mut expected = None;
+            while let Some(window) = scan.next() {
+                if fits(window)
+                    && expected
+                        .as_ref()
+                        .is_none_or(|range: &Range<usize>| range.len() < window.len())
+                {
+                    expected = Some(scan.range());
+                }
+            }
+            assert_eq!(gradient.longest_window_where(fits), expected, "{}", budget);
+        }
+    }
+
+    #[test]
+    fn longest_window_where_respects_max_width() {
+        let gradient = Gradient::with_max_width(vec![0; 10], Some(4));
+        assert_eq!(gradient.longest_window_where(|_| true), Some(0..4));
+        assert_eq!(
+            Gradient::new(Vec::<u8>::new()).longest_window_where(|_| true),
+            None
+        );
+    }
+
+    #[test]
+    fn shortest_window_where_fast_path_agrees_with_scan() {
+        let input = vec![2u32, 0, 7, 1, 1, 4, 0, 9, 3];
+        let gradient = Gradient::from_slice(&input);
+        for target in 0..40 {
+            let reaches = |window: &[u32]| window.iter().sum::<u32>() >= target;
+            let mut scan = gradient.clone();
+            let found = scan.any(reaches);
+            let expected = found.then(|| scan.range());
+            assert_eq!(gradient.shortest_window_where(reaches), expected);
+            assert_eq!(gradient.shortest_window_where_monotone(reaches), expected);
+        }
+    }
+
+    #[test]
+    fn shortest_window_where_respects_max_width() {
+        let gradient = Gradient::with_max_width(vec![1, 1, 1, 1], Some(2));
+        let reaches = |window: &[i32]| window.iter().sum::<i32>() >= 3;
+        assert_eq!(gradient.shortest_window_where(reaches), None);
+        assert_eq!(gradient.shortest_window_where_monotone(reaches), None);
+    }
+
+    #[test]
+    fn failure_table() {
+        assert_eq!(failure(b"aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn find_window_respects_max_width() {
+        let gradient = Gradient::with_max_width(vec![1, 2, 3], Some(2));
+        assert_eq!(gradient.find_window(&[2, 3]), Some(1..3));
+        assert_eq!(gradient.find_window(&[1, 2, 3]), None);
+        assert_eq!(gradient.find_window(&[]), None);
+    }
+}
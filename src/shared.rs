@@ -0,0 +1,164 @@
+use crate::{Gradient, windows_narrower_than};
+use core::ops::Range;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// `SharedGradient` hands out the windows of a gradient to any number
+/// of threads, each window to exactly one of them, see
+/// [Gradient::shared].
+///
+/// Threads claim windows through a shared reference, so it can be
+/// borrowed by scoped threads or wrapped in an [Arc] and moved into
+/// those of an existing thread pool.
+#[derive(Debug)]
+pub struct SharedGradient<G> {
+    input: Arc<[G]>,
+    widest: usize,
+    total: usize,
+    claimed: AtomicUsize,
+}
+impl<G> SharedGradient<G> {
+    /// `new` shares the windows of `input` up to `max_width` wide.
+    pub fn new(input: impl Into<Arc<[G]>>, max_width: Option<usize>) -> SharedGradient<G> {
+        let input = input.into();
+        let widest = max_width.map_or(input.len(), |max_width| max_width.min(input.len()));
+        SharedGradient {
+            total: windows_narrower_than(input.len(), widest + 1),
+            widest,
+            input,
+            claimed: AtomicUsize::new(0),
+        }
+    }
+
+    /// `claim` returns the range of the next window no other thread
+    /// has claimed, in gradient order, or `None` once every window has
+    /// been claimed.
+    pub fn claim(&self) -> Option<Range<usize>> {
+        let index = self
+            .claimed
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |claimed| {
+                (claimed < self.total).then_some(claimed + 1)
+            })
+            .ok()?;
+        Some(self.range_of(index))
+    }
+
+    /// number of windows not claimed yet
+    pub fn remaining(&self) -> usize {
+        self.total - self.claimed.load(Ordering::Relaxed)
+    }
+
+    pub fn input(&self) -> &Arc<[G]> {
+        &self.input
+    }
+
+    /// range of the window at `index` in gradient order
+    fn range_of(&self, index: usize) -> Range<usize> {
+        let len = self.input.len();
+        let (mut low, mut high) = (1, self.widest);
+        while low < high {
+            let width = low + (high - low).div_ceil(2);
+            if windows_narrower_than(len, width) <= index {
+                low = width;
+            } else {
+                high = width - 1;
+            }
+        }
+        let start = index - windows_narrower_than(len, low);
+        start..start + low
+    }
+}
+/// Yields the windows claimed by the thread iterating.
+///
+/// ```
+/// use gradient_slice::SharedGradient;
+/// let shared = SharedGradient::new(vec![1, 2, 3, 4], None);
+/// let sums = std::thread::scope(|scope| {
+///     let workers = (0..3)
+///         .map(|_| scope.spawn(|| (&shared).map(|window| window.iter().sum::<i32>()).sum::<i32>()))
+///         .collect::<Vec<_>>();
+///     workers.into_iter().map(|worker| worker.join().unwrap()).sum::<i32>()
+/// });
+/// assert_eq!(sums, 50);
+/// ```
+impl<'s, G> Iterator for &'s SharedGradient<G> {
+    type Item = &'s [G];
+
+    fn next(&mut self) -> Option<&'s [G]> {
+        let range = self.claim()?;
+        Some(&self.input[range])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining()))
+    }
+}
+
+impl<'a, G: 'a> Gradient<'a, G> {
+    /// `shared` moves the input of the [Gradient](Self) behind an
+    /// [Arc] so that threads can claim its windows from a
+    /// [SharedGradient], starting from the next window it would yield.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut gradient = Gradient::new(vec![1, 2, 3]);
+    /// gradient.next();
+    /// let shared = gradient.shared();
+    /// assert_eq!(shared.claim(), Some(1..2));
+    /// assert_eq!(shared.remaining(), 4);
+    /// ```
+    pub fn shared(self) -> SharedGradient<G> {
+        let yielded = self.yielded();
+        let shared = SharedGradient::new(self.input, self.max_width);
+        shared.claimed.store(yielded, Ordering::Relaxed);
+        shared
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn every_window_is_claimed_once() {
+        for max_width in [None, Some(1), Some(7), Some(100)] {
+            let shared = SharedGradient::new((0..40u16).collect::<Vec<_>>(), max_width);
+            let claimed = std::thread::scope(|scope| {
+                let workers = (0..4)
+                    .map(|_| {
+                        scope.spawn(|| core::iter::from_fn(|| shared.claim()).collect::<Vec<_>>())
+                    })
+                    .collect::<Vec<_>>();
+                workers
+                    .into_iter()
+                    .flat_map(|worker| worker.join().unwrap())
+                    .collect::<Vec<_>>()
+            });
+            let expected = Gradient::with_max_width((0..40u16).collect(), max_width)
+                .rewound()
+                .map(|window| {
+                    let start = window[0] as usize;
+                    start..start + window.len()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(claimed.len(), expected.len());
+            let claimed = claimed
+                .into_iter()
+                .map(|range| (range.len(), range.start))
+                .collect::<BTreeSet<_>>();
+            let expected = expected
+                .into_iter()
+                .map(|range| (range.len(), range.start))
+                .collect();
+            assert_eq!(claimed, expected);
+            assert_eq!((shared.claim(), shared.remaining()), (None, 0));
+        }
+    }
+
+    #[test]
+    fn empty_input_has_nothing_to_claim() {
+        let shared = SharedGradient::<u8>::new(Vec::new(), None);
+        assert_eq!((shared.claim(), (&shared).next()), (None, None));
+    }
+}
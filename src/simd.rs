@@ -0,0 +1,180 @@
+use crate::{Gradient, Sliceable};
+use core::ops::Range;
+use std::simd::cmp::{SimdOrd, SimdPartialEq};
+use std::simd::{Mask, Simd, SimdElement};
+
+/// elements compared per vector
+const LANES: usize = 16;
+
+/// vectors of `T` whose comparisons yield a [Mask]
+trait Lanes<T: SimdElement>: SimdPartialEq<Mask = Mask<T::Mask, LANES>> {}
+impl<T: SimdElement> Lanes<T> for Simd<T, LANES> where
+    Simd<T, LANES>: SimdPartialEq<Mask = Mask<T::Mask, LANES>>
+{
+}
+
+/// `left == right`, a vector of elements at a time
+fn equal<T: SimdElement + PartialEq>(left: &[T], right: &[T]) -> bool
+where
+    Simd<T, LANES>: Lanes<T>,
+{
+    if left.len() != right.len() {
+        return false;
+    }
+    let (left_chunks, right_chunks) = (left.chunks_exact(LANES), right.chunks_exact(LANES));
+    let tails = left_chunks.remainder() == right_chunks.remainder();
+    tails
+        && left_chunks.zip(right_chunks).all(|(a, b)| {
+            Simd::<T, LANES>::from_slice(a)
+                .simd_eq(Simd::from_slice(b))
+                .all()
+        })
+}
+
+/// start of the first occurrence of a non-empty `needle`, candidates
+/// being found by comparing a vector of starts to its first element
+fn find<T: SimdElement + PartialEq>(haystack: &[T], needle: &[T]) -> Option<usize>
+where
+    Simd<T, LANES>: Lanes<T>,
+{
+    let last = haystack.len().checked_sub(needle.len())?;
+    let first = Simd::<T, LANES>::splat(needle[0]);
+    let mut start = 0;
+    while start + LANES <= last + 1 {
+        let mut candidates = Simd::from_slice(&haystack[start..start + LANES])
+            .simd_eq(first)
+            .to_bitmask();
+        while candidates != 0 {
+            let candidate = start + candidates.trailing_zeros() as usize;
+            if equal(&haystack[candidate..candidate + needle.len()], needle) {
+                return Some(candidate);
+            }
+            candidates &= candidates - 1;
+        }
+        start += LANES;
+    }
+    (start..=last).find(|candidate| equal(&haystack[*candidate..candidate + needle.len()], needle))
+}
+
+/// smallest and greatest elements of a non-empty `window`
+fn min_max<T: SimdElement + Ord>(window: &[T]) -> (T, T)
+where
+    Simd<T, LANES>: SimdOrd,
+{
+    let chunks = window.chunks_exact(LANES);
+    let tail = chunks.remainder();
+    let (mut min, mut max) = (window[0], window[0]);
+    if window.len() >= LANES {
+        let (low, high) = chunks.fold(
+            (Simd::splat(window[0]), Simd::splat(window[0])),
+            |(low, high), chunk| {
+                let chunk = Simd::<T, LANES>::from_slice(chunk);
+                (low.simd_min(chunk), high.simd_max(chunk))
+            },
+        );
+        min = low.to_array().into_iter().min().unwrap_or(min);
+        max = high.to_array().into_iter().max().unwrap_or(max);
+    }
+    for element in tail {
+        min = min.min(*element);
+        max = max.max(*element);
+    }
+    (min, max)
+}
+
+/// `SimdMinMax` yields every window of an integer [Gradient] along with
+/// its smallest and greatest elements, see [Gradient::simd_min_max].
+#[derive(Clone, Debug)]
+pub struct SimdMinMax<'a, T, S = Vec<T>> {
+    gradient: Gradient<'a, T, S>,
+}
+
+macro_rules! simd_gradient {
+    ($($element:ty),*) => {$(
+        impl<'a, S: Sliceable<$element>> Iterator for SimdMinMax<'a, $element, S> {
+            type Item = (&'a [$element], $element, $element);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let window = self.gradient.next()?;
+                let (min, max) = min_max(window);
+                Some((window, min, max))
+            }
+        }
+
+        impl<'a, S: Sliceable<$element>> Gradient<'a, $element, S> {
+            /// `simd_find_window` is [find_window](Self::find_window)
+            /// comparing a vector of elements at a time.
+            ///
+            /// ```
+            /// #![feature(portable_simd)]
+            /// use gradient_slice::Gradient;
+            /// let gradient = Gradient::new((0..100u32).collect());
+            /// assert_eq!(gradient.simd_find_window(&[40, 41, 42]), Some(40..43));
+            /// assert_eq!(gradient.simd_find_window(&[41, 40]), None);
+            /// ```
+            pub fn simd_find_window(&self, needle: &[$element]) -> Option<Range<usize>> {
+                if needle.is_empty() || needle.len() > self.widest() {
+                    return None;
+                }
+                let start = find(self.input.as_slice(), needle)?;
+                Some(start..start + needle.len())
+            }
+
+            /// `simd_min_max` pairs every window with its smallest and
+            /// greatest elements, each reduced a vector at a time.
+            ///
+            /// ```
+            /// #![feature(portable_simd)]
+            /// use gradient_slice::Gradient;
+            /// let extremes = Gradient::new(vec![3i8, -7, 5])
+            ///     .simd_min_max()
+            ///     .map(|(_, min, max)| (min, max))
+            ///     .collect::<Vec<_>>();
+            /// assert_eq!(extremes.last(), Some(&(-7, 5)));
+            /// ```
+            pub fn simd_min_max(self) -> SimdMinMax<'a, $element, S> {
+                SimdMinMax { gradient: self }
+            }
+        }
+    )*};
+}
+simd_gradient!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simd_find_window_agrees_with_find_window() {
+        let input = (0..200u16)
+            .map(|index| index % 7 + index / 50)
+            .collect::<Vec<_>>();
+        for max_width in [None, Some(3)] {
+            let gradient = Gradient::with_max_width(input.clone(), max_width);
+            for start in [0, 13, 150, 190, 197] {
+                for len in [1, 2, 3, 17, 40] {
+                    let needle = &input[start..(start + len).min(input.len())];
+                    assert_eq!(
+                        gradient.simd_find_window(needle),
+                        gradient.find_window(needle)
+                    );
+                }
+            }
+            assert_eq!(gradient.simd_find_window(&[999]), None);
+            assert_eq!(gradient.simd_find_window(&[]), gradient.find_window(&[]));
+        }
+    }
+
+    #[test]
+    fn simd_min_max_matches_scalar() {
+        let input = (0..70i64)
+            .map(|index| (index * 37) % 23 - 11)
+            .collect::<Vec<_>>();
+        for (window, min, max) in Gradient::from_slice(&input).simd_min_max() {
+            assert_eq!(Some(&min), window.iter().min());
+            assert_eq!(Some(&max), window.iter().max());
+        }
+        assert!(equal(&input[..40], &input[..40]));
+        assert!(!equal(&input[..40], &input[1..41]));
+    }
+}
@@ -0,0 +1,45 @@
+use alloc::vec::Vec;
+
+/// `Sliceable` is implemented by the storage a
+/// [Gradient](crate::Gradient) can iterate over: anything that gives
+/// contiguous access to its elements, see
+/// [Gradient::from_sliceable](crate::Gradient::from_sliceable).
+///
+/// # Safety
+///
+/// Windows yielded by a gradient outlive the borrow of the gradient
+/// itself, so the slice returned by [as_slice](Self::as_slice) must
+/// keep the same address, length and contents for as long as the
+/// container is alive, even when the container is moved. Heap-backed
+/// containers like `Vec<G>` or `Box<[G]>` qualify, containers storing
+/// their elements inline do not.
+///
+/// ```
+/// use gradient_slice::{Gradient, Sliceable};
+/// struct Chunk {
+///     bytes: Box<[u8]>,
+/// }
+/// unsafe impl Sliceable<u8> for Chunk {
+///     fn as_slice(&self) -> &[u8] {
+///         &self.bytes
+///     }
+/// }
+/// let chunk = Chunk { bytes: Box::new(*b"ab") };
+/// let windows = Gradient::from_sliceable(chunk).map(Vec::from).collect::<Vec<_>>();
+/// assert_eq!(windows, vec![b"a".to_vec(), b"b".to_vec(), b"ab".to_vec()]);
+/// ```
+pub unsafe trait Sliceable<G> {
+    fn as_slice(&self) -> &[G];
+}
+
+unsafe impl<G> Sliceable<G> for Vec<G> {
+    fn as_slice(&self) -> &[G] {
+        self
+    }
+}
+
+unsafe impl<G> Sliceable<G> for &[G] {
+    fn as_slice(&self) -> &[G] {
+        self
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::{self, Vec};
+use core::ops::Range;
+
+/// `Sorted` yields the windows of a [Gradient] in lexicographic order
+/// of their contents, see [Gradient::sorted].
+#[derive(Clone, Debug)]
+pub struct Sorted<'s, G> {
+    input: &'s [G],
+    ranges: vec::IntoIter<Range<usize>>,
+}
+impl<'s, G> Iterator for Sorted<'s, G> {
+    type Item = &'s [G];
+
+    fn next(&mut self) -> Option<&'s [G]> {
+        let range = self.ranges.next()?;
+        Some(&self.input[range])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ranges.size_hint()
+    }
+}
+impl<G> DoubleEndedIterator for Sorted<'_, G> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let range = self.ranges.next_back()?;
+        Some(&self.input[range])
+    }
+}
+impl<G> ExactSizeIterator for Sorted<'_, G> {}
+
+impl<'a, G: Ord + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `sorted` yields every window of the [Gradient](Self) in
+    /// lexicographic order of contents, equal windows in gradient
+    /// order. Only the ranges of the windows are sorted, the windows
+    /// themselves are never cloned, though the ranges are allocated
+    /// all at once.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abab".to_vec());
+    /// let sorted = gradient.sorted().collect::<Vec<&[u8]>>();
+    /// assert_eq!(
+    ///     sorted,
+    ///     vec![&b"a"[..], b"a", b"ab", b"ab", b"aba", b"abab", b"b", b"b", b"ba", b"bab"]
+    /// );
+    /// ```
+    pub fn sorted(&self) -> Sorted<'_, G> {
+        let input = self.input.as_slice();
+        let mut windows = self.rewound();
+        let mut ranges = Vec::with_capacity(self.total());
+        while windows.next().is_some() {
+            ranges.push(windows.range());
+        }
+        ranges.sort_by(|left, right| input[left.clone()].cmp(&input[right.clone()]));
+        Sorted {
+            input,
+            ranges: ranges.into_iter(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_matches_sorting_owned_windows() {
+        let input = b"mississippi".to_vec();
+        let gradient = Gradient::with_max_width(input.clone(), Some(4));
+        let mut owned = Gradient::with_max_width(input, Some(4))
+            .by_ref()
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        owned.sort();
+        let sorted = gradient.sorted();
+        assert_eq!(sorted.len(), owned.len());
+        assert_eq!(sorted.map(Vec::from).collect::<Vec<_>>(), owned);
+        assert_eq!(gradient.sorted().next_back(), Some(&b"ssis"[..]));
+    }
+}
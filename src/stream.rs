@@ -0,0 +1,214 @@
+use alloc::collections::VecDeque;
+use core::ops::Range;
+use std::sync::mpsc::{self, Receiver};
+
+/// `ReceiverGradient` pulls elements from a channel as they arrive,
+/// keeping the latest `max_width` of them, and yields every window
+/// that ends at the newest element, narrowest first.
+///
+/// Windows borrow the internal buffer, so like
+/// [GradientMut](crate::GradientMut) they are lent out one at a time
+/// via [next_window](Self::next_window), which blocks until the next
+/// element arrives and returns `None` once the channel disconnects.
+///
+/// ```
+/// use gradient_slice::ReceiverGradient;
+/// use std::sync::mpsc::channel;
+/// let (sender, receiver) = channel();
+/// for reading in [3, 1, 4, 1] {
+///     sender.send(reading).unwrap();
+/// }
+/// drop(sender);
+/// let mut gradient = ReceiverGradient::new(receiver, 2);
+/// let mut windows = Vec::new();
+/// while let Some(window) = gradient.next_window() {
+///     windows.push(window.to_vec());
+/// }
+/// assert_eq!(
+///     windows,
+///     vec![
+///         vec![3],
+///         vec![1], vec![3, 1],
+///         vec![4], vec![1, 4],
+///         vec![1], vec![4, 1],
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct ReceiverGradient<G, R = mpsc::IntoIter<G>> {
+    receiver: R,
+    buffer: VecDeque<G>,
+    max_width: usize,
+    width: usize,
+    received: usize,
+}
+impl<G> ReceiverGradient<G> {
+    /// `new` creates a [ReceiverGradient](Self) over an
+    /// [mpsc](std::sync::mpsc) [Receiver].
+    ///
+    /// Panics if `max_width` is zero.
+    pub fn new(receiver: Receiver<G>, max_width: usize) -> ReceiverGradient<G> {
+        ReceiverGradient::from_iter(receiver.into_iter(), max_width)
+    }
+}
+#[cfg(feature = "crossbeam")]
+impl<G> ReceiverGradient<G, crossbeam_channel::IntoIter<G>> {
+    /// `from_crossbeam` creates a [ReceiverGradient](Self) over a
+    /// [crossbeam_channel] receiver.
+    ///
+    /// Panics if `max_width` is zero.
+    pub fn from_crossbeam(
+        receiver: crossbeam_channel::Receiver<G>,
+        max_width: usize,
+    ) -> ReceiverGradient<G, crossbeam_channel::IntoIter<G>> {
+        ReceiverGradient::from_iter(receiver.into_iter(), max_width)
+    }
+}
+impl<G, R: Iterator<Item = G>> ReceiverGradient<G, R> {
+    /// `from_source` creates a [ReceiverGradient](Self) over any
+    /// source of elements, such as a generator or the readings of a
+    /// socket, including one that never ends: only the latest
+    /// `max_width` elements are retained.
+    ///
+    /// Panics if `max_width` is zero.
+    ///
+    /// ```
+    /// use gradient_slice::ReceiverGradient;
+    /// let mut gradient = ReceiverGradient::from_source(1.., 2);
+    /// let mut sums = Vec::new();
+    /// while let Some(window) = gradient.next_window() {
+    ///     sums.push(window.iter().sum::<u64>());
+    ///     if sums.len() == 5 {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(sums, vec![1, 2, 3, 3, 5]);
+    /// ```
+    pub fn from_source(
+        source: impl IntoIterator<IntoIter = R>,
+        max_width: usize,
+    ) -> ReceiverGradient<G, R> {
+        ReceiverGradient::from_iter(source.into_iter(), max_width)
+    }
+
+    fn from_iter(receiver: R, max_width: usize) -> ReceiverGradient<G, R> {
+        assert!(max_width > 0, "max_width must be greater than zero");
+        ReceiverGradient {
+            receiver,
+            buffer: VecDeque::with_capacity(max_width),
+            max_width,
+            width: 1,
+            received: 0,
+        }
+    }
+
+    /// `next_window` returns the next window ending at the newest
+    /// element, receiving another element once every such window has
+    /// been returned.
+    pub fn next_window(&mut self) -> Option<&[G]> {
+        if self.width > self.buffer.len() {
+            let element = self.receiver.next()?;
+            if self.buffer.len() == self.max_width {
+                self.buffer.pop_front();
+            }
+            self.buffer.push_back(element);
+            self.received += 1;
+            self.width = 1;
+        }
+        let width = self.width;
+        self.width += 1;
+        if !self.buffer.as_slices().1.is_empty() {
+            self.buffer.make_contiguous();
+        }
+        let buffer = self.buffer.as_slices().0;
+        Some(&buffer[buffer.len() - width..])
+    }
+
+    /// `range` of the window most recently returned by
+    /// [next_window](Self::next_window), counting elements from the
+    /// first one received.
+    pub fn range(&self) -> Range<usize> {
+        self.received + 1 - self.width..self.received
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::sync_channel;
+    use std::thread;
+
+    #[test]
+    fn receiver_gradient_yields_as_data_arrives() {
+        let (sender, receiver) = sync_channel(0);
+        let producer = thread::spawn(move || {
+            for reading in 0..5u32 {
+                sender.send(reading).unwrap();
+            }
+        });
+        let mut gradient = ReceiverGradient::new(receiver, 3);
+        let mut ranges = Vec::new();
+        while let Some(window) = gradient.next_window() {
+            let window = window.to_vec();
+            let range = gradient.range();
+            assert_eq!(
+                window,
+                (range.start as u32..range.end as u32).collect::<Vec<_>>()
+            );
+            ranges.push(range);
+        }
+        producer.join().unwrap();
+        assert_eq!(
+            ranges,
+            vec![
+                0..1,
+                1..2,
+                0..2,
+                2..3,
+                1..3,
+                0..3,
+                3..4,
+                2..4,
+                1..4,
+                4..5,
+                3..5,
+                2..5,
+            ]
+        );
+    }
+
+    #[cfg(feature = "crossbeam")]
+    #[test]
+    fn from_crossbeam_receiver() {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        sender.send('a').unwrap();
+        sender.send('b').unwrap();
+        drop(sender);
+        let mut gradient = ReceiverGradient::from_crossbeam(receiver, 8);
+        let mut count = 0;
+        while gradient.next_window().is_some() {
+            count += 1;
+        }
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn unbounded_source_retains_max_width_elements() {
+        let mut gradient = ReceiverGradient::from_source((0u64..).map(|index| index * 2), 4);
+        for _ in 0..10_000 {
+            let window = gradient.next_window().unwrap().to_vec();
+            let range = gradient.range();
+            assert!(window.len() <= 4 && gradient.buffer.len() <= 4);
+            let expected = (range.start as u64..range.end as u64).map(|index| index * 2);
+            assert_eq!(window, expected.collect::<Vec<_>>());
+        }
+        assert_eq!(gradient.range(), 2500..2502);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_width must be greater than zero")]
+    fn receiver_gradient_requires_max_width() {
+        let (_sender, receiver) = mpsc::channel::<u8>();
+        ReceiverGradient::new(receiver, 0);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{Accumulate, Gradient, Sliceable, WindowAccumulator};
+use alloc::vec::Vec;
+use core::ops::{Add, Sub};
+
+/// `RunningSum` is a [WindowAccumulator] of the sum of the elements in
+/// a window, starting from `G::default()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RunningSum<G>(pub G);
+impl<G: Copy + Add<Output = G> + Sub<Output = G>> WindowAccumulator<G> for RunningSum<G> {
+    type Output = G;
+
+    fn add(&mut self, element: &G) {
+        self.0 = self.0 + *element;
+    }
+
+    fn remove(&mut self, element: &G) {
+        self.0 = self.0 - *element;
+    }
+
+    fn value(&self) -> G {
+        self.0
+    }
+}
+
+/// `WhereSum` yields the windows of a [Gradient] whose sum satisfies a
+/// predicate, along with that sum, see [Gradient::where_sum].
+#[derive(Clone, Debug)]
+pub struct WhereSum<'a, G, F, S = Vec<G>> {
+    sums: Accumulate<'a, G, RunningSum<G>, S>,
+    predicate: F,
+    monotone: bool,
+    width: usize,
+    matched: bool,
+}
+impl<'a, G, F, S> WhereSum<'a, G, F, S> {
+    /// `monotone` declares that the elements are never negative and
+    /// that the predicate, once failing for a sum, fails for every
+    /// greater sum, as with `sum <= budget`. Wider windows then never
+    /// pass once every window of a width failed, so iteration ends
+    /// there instead of scanning the remaining widths.
+    pub fn monotone(mut self) -> WhereSum<'a, G, F, S> {
+        self.monotone = true;
+        self
+    }
+}
+impl<'a, G, F, S> Iterator for WhereSum<'a, G, F, S>
+where
+    G: Copy + Default + Add<Output = G> + Sub<Output = G> + 'a,
+    F: FnMut(&G) -> bool,
+    S: Sliceable<G>,
+{
+    type Item = (&'a [G], G);
+
+    fn next(&mut self) -> Option<(&'a [G], G)> {
+        loop {
+            let (window, sum) = self.sums.next()?;
+            if window.len() != self.width {
+                if self.monotone && self.width > 0 && !self.matched {
+                    return None;
+                }
+                self.width = window.len();
+                self.matched = false;
+            }
+            if (self.predicate)(&sum) {
+                self.matched = true;
+                return Some((window, sum));
+            }
+        }
+    }
+}
+
+impl<'a, G, S> Gradient<'a, G, S>
+where
+    G: Copy + Default + Add<Output = G> + Sub<Output = G> + 'a,
+    S: Sliceable<G>,
+{
+    /// `where_sum` yields only the windows whose sum satisfies
+    /// `predicate`, paired with that sum, maintained incrementally as
+    /// in [accumulate](Self::accumulate).
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let costs = [4u32, 1, 2, 7, 1];
+    /// let affordable = Gradient::from_slice(&costs)
+    ///     .where_sum(|sum| *sum <= 5)
+    ///     .monotone()
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     affordable,
+    ///     vec![
+    ///         (&costs[0..1], 4), (&costs[1..2], 1), (&costs[2..3], 2), (&costs[4..5], 1),
+    ///         (&costs[0..2], 5), (&costs[1..3], 3),
+    ///     ]
+    /// );
+    /// ```
+    pub fn where_sum<F: FnMut(&G) -> bool>(self, predicate: F) -> WhereSum<'a, G, F, S> {
+        WhereSum {
+            sums: self.accumulate(RunningSum::default()),
+            predicate,
+            monotone: false,
+            width: 0,
+            matched: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn where_sum_matches_filtering_each_window() {
+        let input = [3i64, -2, 5, 0, -7, 4, 4];
+        let expected = Gradient::from_slice(&input)
+            .map(|window| (window, window.iter().sum::<i64>()))
+            .filter(|(_, sum)| sum % 2 == 0)
+            .collect::<Vec<_>>();
+        let found = Gradient::from_slice(&input)
+            .where_sum(|sum| sum % 2 == 0)
+            .collect::<Vec<_>>();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn monotone_stops_past_the_widest_affordable_width() {
+        let input = [2.5f64, 0.5, 3.0, 1.0];
+        let mut calls = 0;
+        let widths = Gradient::from_slice(&input)
+            .where_sum(|sum| {
+                calls += 1;
+                *sum <= 3.5
+            })
+            .monotone()
+            .map(|(window, _)| window.len())
+            .collect::<Vec<_>>();
+        assert_eq!(widths, vec![1, 1, 1, 1, 2, 2]);
+        assert_eq!(calls, 4 + 3 + 2);
+    }
+}
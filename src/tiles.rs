@@ -0,0 +1,83 @@
+use crate::gradient_nd::Boxes;
+use image::{GenericImageView, SubImage};
+
+/// `Tiles` iterates over sub-image views of progressively larger
+/// tiles of an [image], in the order of [Gradient2D](crate::Gradient2D):
+/// increasing height, then width, then top to bottom, left to right.
+///
+/// ```
+/// use gradient_slice::Tiles;
+/// use image::{GenericImageView, GrayImage};
+/// let image = GrayImage::from_raw(3, 2, vec![0, 1, 2, 3, 4, 5]).unwrap();
+/// let tiles = Tiles::new(&image).square().collect::<Vec<_>>();
+/// assert_eq!(tiles.len(), 8);
+/// let last = tiles.last().unwrap();
+/// assert_eq!((last.offsets(), last.get_pixel(1, 1).0), ((1, 0), [5]));
+/// ```
+pub struct Tiles<'i, I> {
+    image: &'i I,
+    boxes: Boxes<2>,
+    square: bool,
+}
+impl<'i, I: GenericImageView> Tiles<'i, I> {
+    pub fn new(image: &'i I) -> Tiles<'i, I> {
+        let (width, height) = image.dimensions();
+        Tiles {
+            image,
+            boxes: Boxes::new([height as usize, width as usize]),
+            square: false,
+        }
+    }
+
+    /// `square` only keeps tiles as wide as they are tall.
+    pub fn square(mut self) -> Tiles<'i, I> {
+        self.square = true;
+        self
+    }
+}
+impl<'i, I: GenericImageView> Iterator for Tiles<'i, I> {
+    type Item = SubImage<&'i I>;
+
+    fn next(&mut self) -> Option<SubImage<&'i I>> {
+        let square = self.square;
+        let ([y, x], [height, width]) = self
+            .boxes
+            .by_ref()
+            .find(|(_, [height, width])| !square || height == width)?;
+        Some(SubImage::new(
+            self.image,
+            x as u32,
+            y as u32,
+            width as u32,
+            height as u32,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+
+    #[test]
+    fn tiles_cover_every_rectangle() {
+        let image = GrayImage::from_fn(4, 3, |x, y| Luma([(y * 4 + x) as u8]));
+        let tiles = Tiles::new(&image).collect::<Vec<_>>();
+        assert_eq!(tiles.len(), 6 * 10);
+        assert_eq!(tiles[0].offsets(), (0, 0));
+        assert_eq!(tiles[1].offsets(), (1, 0));
+        let pixels = tiles[tiles.len() - 1].to_image().into_raw();
+        assert_eq!(pixels, image.into_raw());
+    }
+
+    #[test]
+    fn square_tiles() {
+        let image = GrayImage::new(3, 3);
+        let sizes = Tiles::new(&image)
+            .square()
+            .map(|tile| tile.dimensions())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes.len(), 9 + 4 + 1);
+        assert!(sizes.iter().all(|(width, height)| width == height));
+    }
+}
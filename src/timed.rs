@@ -0,0 +1,126 @@
+use crate::Gradient;
+use core::ops::{RangeInclusive, Sub};
+
+/// `Timed` pairs each window of a [Gradient] with the interval of
+/// timestamps it covers, see [Gradient::with_timestamps].
+#[derive(Clone, Debug)]
+pub struct Timed<'a, G, T> {
+    gradient: Gradient<'a, G, &'a [G]>,
+    times: &'a [T],
+    max_duration: Option<T>,
+    width: usize,
+    matched: bool,
+}
+impl<'a, G: 'a, T: Copy + PartialOrd + Sub<Output = T>> Timed<'a, G, T> {
+    /// `max_duration` only yields windows whose last timestamp is at
+    /// most `duration` after their first, regardless of their width.
+    ///
+    /// Timestamps must be sorted in ascending order, so that once no
+    /// window of some width fits, no wider window does either.
+    pub fn max_duration(mut self, duration: T) -> Timed<'a, G, T> {
+        self.max_duration = Some(duration);
+        self
+    }
+}
+impl<'a, G: 'a, T: Copy + PartialOrd + Sub<Output = T>> Iterator for Timed<'a, G, T> {
+    type Item = (&'a [G], RangeInclusive<T>);
+
+    fn next(&mut self) -> Option<(&'a [G], RangeInclusive<T>)> {
+        loop {
+            let window = self.gradient.next()?;
+            if self.gradient.width() != self.width {
+                if self.width > 0 && !self.matched {
+                    return None;
+                }
+                self.width = self.gradient.width();
+                self.matched = false;
+            }
+            let range = self.gradient.range();
+            let (first, last) = (self.times[range.start], self.times[range.end - 1]);
+            if let Some(max_duration) = self.max_duration {
+                if last - first > max_duration {
+                    continue;
+                }
+            }
+            self.matched = true;
+            return Some((window, first..=last));
+        }
+    }
+}
+
+impl<'a, G: 'a> Gradient<'a, G, &'a [G]> {
+    /// `with_timestamps` creates a [Timed] gradient over `values`
+    /// where `times[i]` is the timestamp of `values[i]`.
+    ///
+    /// Panics if `values` and `times` differ in length.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let values = [1.5, 2.0, 0.5, 4.0];
+    /// let times = [100u64, 110, 150, 155];
+    /// let windows = Gradient::with_timestamps(&values, &times)
+    ///     .max_duration(10)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     windows,
+    ///     vec![
+    ///         (&values[0..1], 100..=100),
+    ///         (&values[1..2], 110..=110),
+    ///         (&values[2..3], 150..=150),
+    ///         (&values[3..4], 155..=155),
+    ///         (&values[0..2], 100..=110),
+    ///         (&values[2..4], 150..=155),
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_timestamps<T>(values: &'a [G], times: &'a [T]) -> Timed<'a, G, T> {
+        assert_eq!(
+            values.len(),
+            times.len(),
+            "every value needs exactly one timestamp"
+        );
+        Timed {
+            gradient: Gradient::from_slice(values),
+            times,
+            max_duration: None,
+            width: 0,
+            matched: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn with_timestamps_covers_every_window() {
+        let values = "abcd".chars().collect::<Vec<char>>();
+        let times = [0, 1, 2, 3];
+        let timed = Gradient::with_timestamps(&values, &times).collect::<Vec<_>>();
+        let windows = Gradient::from_slice(&values).collect::<Vec<_>>();
+        assert_eq!(timed.len(), windows.len());
+        for ((window, interval), expected) in timed.into_iter().zip(windows) {
+            assert_eq!(window, expected);
+            assert_eq!(interval.end() - interval.start() + 1, window.len());
+        }
+    }
+
+    #[test]
+    fn max_duration_stops_once_no_width_fits() {
+        let values = [0u8; 6];
+        let times = [0, 5, 10, 40, 41, 42];
+        let widths = Gradient::with_timestamps(&values, &times)
+            .max_duration(5)
+            .map(|(window, _)| window.len())
+            .collect::<Vec<_>>();
+        assert_eq!(widths, vec![1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "every value needs exactly one timestamp")]
+    fn with_timestamps_requires_matching_lengths() {
+        Gradient::with_timestamps(&[1, 2], &[0]);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{Gradient, Sliceable};
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
+
+/// a kept window, ordered so that the heap's greatest entry is the
+/// worst one: the lowest score, then the latest window
+struct Entry<T> {
+    score: T,
+    order: usize,
+    range: Range<usize>,
+}
+impl<T: PartialOrd> Ord for Entry<T> {
+    fn cmp(&self, other: &Entry<T>) -> Ordering {
+        other
+            .score
+            .partial_cmp(&self.score)
+            .unwrap_or(Ordering::Equal)
+            .then(self.order.cmp(&other.order))
+    }
+}
+impl<T: PartialOrd> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Entry<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T: PartialOrd> PartialEq for Entry<T> {
+    fn eq(&self, other: &Entry<T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<T: PartialOrd> Eq for Entry<T> {}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `top_k` returns the ranges of the `k` windows with the highest
+    /// `score`, paired with it, best first. Among equal scores the
+    /// window yielded first ranks higher.
+    ///
+    /// Windows stream through a min-heap of the `k` best so far, so
+    /// only `k` scores are ever kept. Scores that don't compare with
+    /// themselves, like `f64::NAN`, are skipped.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(vec![3.0, -1.0, 2.0]);
+    /// let top = gradient.top_k(2, |window| window.iter().sum::<f64>());
+    /// assert_eq!(top, vec![(0..3, 4.0), (0..1, 3.0)]);
+    /// ```
+    pub fn top_k<T: PartialOrd, F: FnMut(&[G]) -> T>(
+        &self,
+        k: usize,
+        mut score: F,
+    ) -> Vec<(Range<usize>, T)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap = BinaryHeap::with_capacity(k);
+        let mut gradient = self.rewound();
+        let mut order = 0;
+        while let Some(window) = gradient.next() {
+            let score = score(window);
+            order += 1;
+            if score.partial_cmp(&score).is_none() {
+                continue;
+            }
+            if heap.len() == k {
+                let worst: &Entry<T> = heap.peek().expect("k is positive");
+                if score.partial_cmp(&worst.score) != Some(Ordering::Greater) {
+                    continue;
+                }
+                heap.pop();
+            }
+            heap.push(Entry {
+                score,
+                order,
+                range: gradient.range(),
+            });
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|entry| (entry.range, entry.score))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_k_agrees_with_sorting_every_window() {
+        let input = [5u8, 1, 4, 4, 0, 9, 2, 2, 7];
+        let gradient = Gradient::with_max_width(input.to_vec(), Some(5));
+        let score = |window: &[u8]| window.iter().map(|value| *value as u32).sum::<u32>() % 11;
+        let mut all = Vec::new();
+        let mut windows = gradient.rewound();
+        while let Some(window) = windows.next() {
+            all.push((windows.range(), score(window)));
+        }
+        all.sort_by_key(|(_, score)| core::cmp::Reverse(*score));
+        for k in [0, 1, 3, 7, all.len(), all.len() + 5] {
+            let expected = all.iter().take(k).cloned().collect::<Vec<_>>();
+            assert_eq!(gradient.top_k(k, score), expected);
+        }
+    }
+
+    #[test]
+    fn top_k_skips_nan() {
+        let gradient = Gradient::new(vec![1.0, f64::NAN]);
+        assert_eq!(
+            gradient.top_k(5, |window| window[0]),
+            vec![(0..1, 1.0), (0..2, 1.0)]
+        );
+    }
+}
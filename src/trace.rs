@@ -0,0 +1,161 @@
+use crate::{Gradient, Sliceable};
+use alloc::vec::Vec;
+use tracing::span::EnteredSpan;
+
+/// `Traced` is an iterator adaptor that records a [tracing] span per
+/// width pass, see [Gradient::traced].
+///
+/// The span of the current pass stays entered between calls to `next`
+/// so that the consumer's work on each window is attributed to it,
+/// which also makes `Traced` neither `Send` nor `Sync`.
+pub struct Traced<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    every_n: Option<usize>,
+    yielded: usize,
+    total: usize,
+    pass: Option<(usize, EnteredSpan)>,
+}
+impl<'a, G: 'a, S: Sliceable<G>> Iterator for Traced<'a, G, S> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        let Some(window) = self.gradient.next() else {
+            self.pass = None;
+            return None;
+        };
+        self.yielded += 1;
+        let width = window.len();
+        if self.pass.as_ref().map(|(pass, _)| *pass) != Some(width) {
+            self.pass = None;
+            let windows = self.gradient.len() + 1 - width;
+            let span = tracing::debug_span!("gradient_pass", width, windows);
+            self.pass = Some((width, span.entered()));
+        }
+        if let Some(every_n) = self.every_n {
+            if self.yielded % every_n == 0 {
+                tracing::debug!(
+                    yielded = self.yielded,
+                    total = self.total,
+                    start = self.gradient.start(),
+                    "gradient progress"
+                );
+            }
+        }
+        Some(window)
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `traced` opens a `gradient_pass` span for each width pass,
+    /// closed when the next pass begins, and emits a `gradient
+    /// progress` event inside it every `every_n` windows when given.
+    ///
+    /// Panics if `every_n` is `Some(0)`.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let count = Gradient::new(vec![1, 2, 3]).traced(Some(2)).count();
+    /// assert_eq!(count, 6);
+    /// ```
+    pub fn traced(self, every_n: Option<usize>) -> Traced<'a, G, S> {
+        assert!(every_n != Some(0), "every_n must be greater than zero");
+        Traced {
+            yielded: self.yielded(),
+            total: self.total(),
+            gradient: self,
+            every_n,
+            pass: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Debug;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    #[derive(Default)]
+    struct Capture {
+        lines: Arc<Mutex<Vec<String>>>,
+        ids: AtomicU64,
+    }
+
+    struct Fields(String);
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields(format!("span {}", span.metadata().name()));
+            span.record(&mut fields);
+            self.lines.lock().unwrap().push(fields.0);
+            Id::from_u64(self.ids.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields(String::from("event"));
+            event.record(&mut fields);
+            self.lines.lock().unwrap().push(fields.0);
+        }
+
+        fn enter(&self, span: &Id) {
+            self.lines
+                .lock()
+                .unwrap()
+                .push(format!("enter {}", span.into_u64()));
+        }
+
+        fn exit(&self, span: &Id) {
+            self.lines
+                .lock()
+                .unwrap()
+                .push(format!("exit {}", span.into_u64()));
+        }
+    }
+
+    #[test]
+    fn traced_spans_each_width_pass() {
+        let capture = Capture::default();
+        let lines = capture.lines.clone();
+        let widths = tracing::subscriber::with_default(capture, || {
+            Gradient::new(vec![1, 2, 3])
+                .traced(Some(2))
+                .map(<[i32]>::len)
+                .collect::<Vec<usize>>()
+        });
+        assert_eq!(widths, vec![1, 1, 1, 2, 2, 3]);
+        assert_eq!(
+            *lines.lock().unwrap(),
+            vec![
+                "span gradient_pass width=1 windows=3",
+                "enter 1",
+                "event message=gradient progress yielded=2 total=6 start=1",
+                "exit 1",
+                "span gradient_pass width=2 windows=2",
+                "enter 2",
+                "event message=gradient progress yielded=4 total=6 start=0",
+                "exit 2",
+                "span gradient_pass width=3 windows=1",
+                "enter 3",
+                "event message=gradient progress yielded=6 total=6 start=0",
+                "exit 3",
+            ]
+        );
+    }
+}
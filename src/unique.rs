@@ -0,0 +1,130 @@
+use crate::rolling::{element_hashes, width_hashes};
+use crate::{Gradient, Sliceable};
+use core::hash::{BuildHasher, Hash};
+use core::ops::Range;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+
+/// `Unique` suppresses windows whose contents were already yielded,
+/// see [Gradient::unique].
+pub struct Unique<'a, G, S = Vec<G>> {
+    gradient: Gradient<'a, G, S>,
+    seen: HashMap<u64, Vec<Range<usize>>>,
+    hasher: RandomState,
+}
+impl<'a, G: Hash + Eq + 'a, S: Sliceable<G>> Iterator for Unique<'a, G, S> {
+    type Item = &'a [G];
+
+    fn next(&mut self) -> Option<&'a [G]> {
+        loop {
+            let window = self.gradient.next()?;
+            let ranges = self.seen.entry(self.hasher.hash_one(window)).or_default();
+            let input = self.gradient.input.as_slice();
+            if ranges.iter().any(|range| input[range.clone()] == *window) {
+                continue;
+            }
+            ranges.push(self.gradient.range());
+            return Some(window);
+        }
+    }
+}
+
+impl<'a, G: Hash + Eq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `unique` yields only the first occurrence of every distinct
+    /// window. Contents are hashed and only the range of each yielded
+    /// window is kept, so nothing is cloned.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let unique = Gradient::new(b"aaab".to_vec())
+    ///     .unique()
+    ///     .map(Vec::from)
+    ///     .collect::<Vec<Vec<u8>>>();
+    /// assert_eq!(
+    ///     unique,
+    ///     vec![
+    ///         b"a".to_vec(), b"b".to_vec(),
+    ///         b"aa".to_vec(), b"ab".to_vec(),
+    ///         b"aaa".to_vec(), b"aab".to_vec(),
+    ///         b"aaab".to_vec(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn unique(self) -> Unique<'a, G, S> {
+        Unique {
+            gradient: self,
+            seen: HashMap::new(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// `count_unique` returns the number of distinct window contents
+    /// the [Gradient](Self) yields, or only among windows of `width`
+    /// when given.
+    ///
+    /// Windows are compared by a rolling hash updated in `O(1)` per
+    /// window within a width, so two distinct windows are counted once
+    /// with probability around `n / 2^61` per width.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abab".to_vec());
+    /// assert_eq!(gradient.count_unique(None), 2 + 2 + 2 + 1);
+    /// assert_eq!(gradient.count_unique(Some(2)), 2);
+    /// ```
+    pub fn count_unique(&self, width: Option<usize>) -> usize {
+        let elements = element_hashes(self.input.as_slice());
+        let widths = match width {
+            Some(width) if width == 0 || width > self.widest() => 0..0,
+            Some(width) => width..width + 1,
+            None => 1..self.widest() + 1,
+        };
+        let mut distinct = HashSet::new();
+        widths
+            .map(|width| {
+                distinct.clear();
+                distinct.extend(width_hashes(&elements, width));
+                distinct.len()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn unique_matches_owned_set() {
+        let input = b"acgtacgtaacg";
+        let mut seen = HashSet::new();
+        let expected = Gradient::from_slice(input)
+            .filter(|window| seen.insert(window.to_vec()))
+            .collect::<Vec<_>>();
+        let unique = Gradient::from_slice(input).unique().collect::<Vec<_>>();
+        assert_eq!(unique, expected);
+    }
+
+    #[test]
+    fn count_unique_matches_unique() {
+        let input = b"acgtacgtaacg".to_vec();
+        let gradient = Gradient::with_max_width(input.clone(), Some(6));
+        let unique = Gradient::with_max_width(input, Some(6)).unique().count();
+        assert_eq!(gradient.count_unique(None), unique);
+        assert_eq!(gradient.count_unique(Some(1)), 4);
+        assert_eq!(gradient.count_unique(Some(7)), 0);
+        assert_eq!(gradient.count_unique(Some(0)), 0);
+    }
+
+    #[test]
+    fn unique_compares_contents_of_colliding_hashes() {
+        #[derive(PartialEq, Eq)]
+        struct Collides(u8);
+        impl Hash for Collides {
+            fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+        }
+        let input = [Collides(1), Collides(2), Collides(1)];
+        assert_eq!(Gradient::from_slice(&input).unique().count(), 2 + 2 + 1);
+    }
+}
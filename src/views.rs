@@ -0,0 +1,83 @@
+use crate::gradient_nd::Boxes;
+use ndarray::{ArrayView, Dim, Dimension, Slice};
+
+/// `ViewGradient` iterates over every hyper-rectangular sub-view of an
+/// [ndarray] view in the order of [GradientNd](crate::GradientNd),
+/// yielding [ArrayView]s that keep the strides of the original view.
+///
+/// ```
+/// use gradient_slice::ViewGradient;
+/// use ndarray::array;
+/// let matrix = array![[1, 2], [3, 4]];
+/// let views = ViewGradient::new(matrix.view()).collect::<Vec<_>>();
+/// assert_eq!(views.len(), 9);
+/// assert_eq!(views[6], array![[1], [3]]);
+/// assert_eq!(views[6].strides()[0], matrix.strides()[0]);
+/// ```
+pub struct ViewGradient<'v, A, const N: usize>
+where
+    Dim<[usize; N]>: Dimension,
+{
+    view: ArrayView<'v, A, Dim<[usize; N]>>,
+    boxes: Boxes<N>,
+}
+impl<'v, A, const N: usize> ViewGradient<'v, A, N>
+where
+    Dim<[usize; N]>: Dimension,
+{
+    /// `new` creates a [ViewGradient](Self) over an `ArrayView1`,
+    /// `ArrayView2` or any other fixed-dimension view.
+    pub fn new(view: ArrayView<'v, A, Dim<[usize; N]>>) -> ViewGradient<'v, A, N> {
+        let mut shape = [0; N];
+        shape.copy_from_slice(view.shape());
+        ViewGradient {
+            view,
+            boxes: Boxes::new(shape),
+        }
+    }
+}
+impl<'v, A, const N: usize> Iterator for ViewGradient<'v, A, N>
+where
+    Dim<[usize; N]>: Dimension,
+{
+    type Item = ArrayView<'v, A, Dim<[usize; N]>>;
+
+    fn next(&mut self) -> Option<ArrayView<'v, A, Dim<[usize; N]>>> {
+        let (origin, size) = self.boxes.next()?;
+        let mut window = self.view;
+        window.slice_each_axis_inplace(|axis| {
+            let axis = axis.axis.index();
+            Slice::from(origin[axis]..origin[axis] + size[axis])
+        });
+        Some(window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array1, s};
+
+    #[test]
+    fn view_gradient_1d_matches_gradient() {
+        let input = Array1::from(" abc ".chars().collect::<Vec<char>>());
+        let views = ViewGradient::new(input.view())
+            .map(|view| view.to_vec())
+            .collect::<Vec<_>>();
+        let windows = crate::Gradient::from_slice(input.as_slice().unwrap())
+            .map(Vec::from)
+            .collect::<Vec<_>>();
+        assert_eq!(views, windows);
+    }
+
+    #[test]
+    fn view_gradient_keeps_strides_of_transposed_views() {
+        let matrix = ndarray::Array2::from_shape_vec((2, 3), (0..6).collect::<Vec<u8>>()).unwrap();
+        let transposed = matrix.t();
+        let views = ViewGradient::new(transposed).collect::<Vec<_>>();
+        assert_eq!(views.len(), 6 * 3);
+        let column = views.iter().find(|view| view.shape() == [3, 1]).unwrap();
+        assert_eq!(*column, matrix.slice(s![0..1, ..]).t());
+        assert_eq!(column.strides()[0], transposed.strides()[0]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::Gradient;
+use wasm_bindgen::prelude::*;
+
+enum Input {
+    Bytes(Gradient<'static, u8>),
+    Chars(Gradient<'static, char>),
+}
+
+/// `GradientJs` exposes a [Gradient] over bytes or the characters of
+/// a string to JavaScript through [wasm_bindgen].
+///
+/// Windows come out as `Uint8Array`s from `nextBytes` and as strings
+/// from `nextString`, whichever way the input was given; strings are
+/// UTF-8 encoded into bytes and bytes are decoded lossily into strings.
+#[wasm_bindgen]
+pub struct GradientJs {
+    input: Input,
+}
+
+#[wasm_bindgen]
+impl GradientJs {
+    /// `fromBytes` creates a gradient over the bytes of a `Uint8Array`.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8], max_width: Option<usize>) -> GradientJs {
+        GradientJs {
+            input: Input::Bytes(Gradient::with_max_width(bytes.to_vec(), max_width)),
+        }
+    }
+
+    /// `fromString` creates a gradient over the characters of a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(string: &str, max_width: Option<usize>) -> GradientJs {
+        GradientJs {
+            input: Input::Chars(Gradient::with_max_width(
+                string.chars().collect(),
+                max_width,
+            )),
+        }
+    }
+
+    /// `nextBytes` returns the next window as a `Uint8Array`.
+    #[wasm_bindgen(js_name = nextBytes)]
+    pub fn next_bytes(&mut self) -> Option<Vec<u8>> {
+        match &mut self.input {
+            Input::Bytes(gradient) => gradient.next().map(Vec::from),
+            Input::Chars(gradient) => gradient
+                .next()
+                .map(|window| window.iter().collect::<String>().into_bytes()),
+        }
+    }
+
+    /// `nextString` returns the next window as a string.
+    #[wasm_bindgen(js_name = nextString)]
+    pub fn next_string(&mut self) -> Option<String> {
+        match &mut self.input {
+            Input::Bytes(gradient) => gradient
+                .next()
+                .map(|window| String::from_utf8_lossy(window).into_owned()),
+            Input::Chars(gradient) => gradient.next().map(|window| window.iter().collect()),
+        }
+    }
+
+    /// `start` is the offset of the current window's first element.
+    #[wasm_bindgen(getter)]
+    pub fn start(&self) -> usize {
+        match &self.input {
+            Input::Bytes(gradient) => gradient.start(),
+            Input::Chars(gradient) => gradient.start(),
+        }
+    }
+
+    /// `end` is the offset past the current window's last element.
+    #[wasm_bindgen(getter)]
+    pub fn end(&self) -> usize {
+        match &self.input {
+            Input::Bytes(gradient) => gradient.end(),
+            Input::Chars(gradient) => gradient.end(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_string_yields_strings_and_bytes() {
+        let mut gradient = GradientJs::from_string("aé", None);
+        assert_eq!(gradient.next_string().as_deref(), Some("a"));
+        assert_eq!(gradient.next_bytes(), Some("é".as_bytes().to_vec()));
+        assert_eq!((gradient.start(), gradient.end()), (1, 2));
+        assert_eq!(gradient.next_string().as_deref(), Some("aé"));
+        assert_eq!(gradient.next_string(), None);
+    }
+
+    #[test]
+    fn from_bytes_respects_max_width() {
+        let mut gradient = GradientJs::from_bytes(&[0x1B, 0xAD, 0xB0], Some(2));
+        let mut windows = Vec::new();
+        while let Some(window) = gradient.next_bytes() {
+            windows.push(window);
+        }
+        assert_eq!(
+            windows,
+            vec![
+                vec![0x1B],
+                vec![0xAD],
+                vec![0xB0],
+                vec![0x1B, 0xAD],
+                vec![0xAD, 0xB0]
+            ]
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Gradient, Sliceable};
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// `WithoutRepeats` yields the maximal windows of a [Gradient] in
+/// which no element occurs twice, see [Gradient::windows_without_repeats].
+pub struct WithoutRepeats<'s, G> {
+    input: &'s [G],
+    widest: usize,
+    /// position of the latest occurrence of every element seen so far
+    latest: HashMap<&'s G, usize>,
+    start: usize,
+    end: usize,
+}
+impl<'s, G: Hash + Eq> Iterator for WithoutRepeats<'s, G> {
+    type Item = &'s [G];
+
+    fn next(&mut self) -> Option<&'s [G]> {
+        while self.end < self.input.len() {
+            let element = &self.input[self.end];
+            if let Some(previous) = self.latest.insert(element, self.end) {
+                self.start = self.start.max(previous + 1);
+            }
+            self.end += 1;
+            self.start = self.start.max(self.end.saturating_sub(self.widest));
+            let window = &self.input[self.start..self.end];
+            let extends = self.input.get(self.end).is_some_and(|next| {
+                window.len() < self.widest
+                    && self.latest.get(next).is_none_or(|at| *at < self.start)
+            });
+            if !extends && !window.is_empty() {
+                return Some(window);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, G: Hash + Eq + 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `windows_without_repeats` yields every window in which no
+    /// element occurs twice and that no wider such window contains,
+    /// by ascending start. Windows are capped at `max_width`.
+    ///
+    /// A single pass slides a window whose start jumps past the latest
+    /// occurrence of each entering element, so the whole input costs
+    /// `O(n)` hash lookups.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abcabd".to_vec());
+    /// assert_eq!(
+    ///     gradient.windows_without_repeats().collect::<Vec<_>>(),
+    ///     vec![&b"abc"[..], b"bca", b"cabd"]
+    /// );
+    /// ```
+    pub fn windows_without_repeats(&self) -> WithoutRepeats<'_, G> {
+        WithoutRepeats {
+            input: self.input.as_slice(),
+            widest: self.widest(),
+            latest: HashMap::new(),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// `longest_window_without_repeats` returns the widest window in
+    /// which no element occurs twice, the first one among equally wide
+    /// windows, see [windows_without_repeats](Self::windows_without_repeats).
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let gradient = Gradient::new(b"abcabcbb".to_vec());
+    /// assert_eq!(gradient.longest_window_without_repeats(), Some(&b"abc"[..]));
+    /// let gradient = Gradient::with_max_width(b"abcdef".to_vec(), Some(2));
+    /// assert_eq!(gradient.longest_window_without_repeats(), Some(&b"ab"[..]));
+    /// ```
+    pub fn longest_window_without_repeats(&self) -> Option<&[G]> {
+        self.windows_without_repeats()
+            .fold(None, |longest: Option<&[G]>, window| match longest {
+                Some(longest) if longest.len() >= window.len() => Some(longest),
+                _ => Some(window),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn without_repeats(window: &[u8]) -> bool {
+        window
+            .iter()
+            .enumerate()
+            .all(|(index, element)| !window[index + 1..].contains(element))
+    }
+
+    #[test]
+    fn windows_without_repeats_agrees_with_naive_search() {
+        for input in ["abcabcbb", "pwwkew", "aaaa", "", "abcdefg", "abba"] {
+            for max_width in [None, Some(1), Some(3)] {
+                let input = input.as_bytes();
+                let gradient = Gradient::with_max_width(input.to_vec(), max_width);
+                let candidates = Gradient::from_slice(input)
+                    .filter(|window| window.len() <= gradient.widest() && without_repeats(window))
+                    .map(|window| {
+                        (
+                            window.as_ptr() as usize - input.as_ptr() as usize,
+                            window.len(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let mut expected = candidates
+                    .iter()
+                    .filter(|(start, len)| {
+                        !candidates.iter().any(|(other, other_len)| {
+                            other_len > len && other <= start && other + other_len >= start + len
+                        })
+                    })
+                    .map(|(start, len)| &input[*start..start + len])
+                    .collect::<Vec<_>>();
+                expected.sort_by_key(|window| window.as_ptr());
+                let found = gradient
+                    .windows_without_repeats()
+                    .map(|window| window.to_vec())
+                    .collect::<Vec<_>>();
+                assert_eq!(found, expected);
+            }
+        }
+    }
+}
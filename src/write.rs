@@ -0,0 +1,128 @@
+use crate::{Gradient, Sliceable};
+use std::io::{self, BufWriter, Write};
+
+/// writes every remaining window of `gradient` with `write_window`,
+/// `delimiter` between windows of the same width and `terminator`
+/// after the last window of each width
+fn write_windows<'a, G: 'a, S: Sliceable<G>, W: Write>(
+    gradient: Gradient<'a, G, S>,
+    writer: W,
+    delimiter: &[u8],
+    terminator: &[u8],
+    mut write_window: impl FnMut(&mut BufWriter<W>, &[G]) -> io::Result<()>,
+) -> io::Result<usize> {
+    let mut writer = BufWriter::new(writer);
+    let mut written = 0;
+    let mut width = 0;
+    for window in gradient {
+        if window.len() != width && written > 0 {
+            writer.write_all(terminator)?;
+        } else if written > 0 {
+            writer.write_all(delimiter)?;
+        }
+        width = window.len();
+        write_window(&mut writer, window)?;
+        written += 1;
+    }
+    if written > 0 {
+        writer.write_all(terminator)?;
+    }
+    writer.flush()?;
+    Ok(written)
+}
+
+impl<'a, S: Sliceable<u8>> Gradient<'a, u8, S> {
+    /// `write_to` streams every remaining window into `writer` through
+    /// a buffer, `delimiter` between the windows of a width and
+    /// `terminator` after the last one, and returns the number of
+    /// windows written.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut output = Vec::new();
+    /// let written = Gradient::new(b"abc".to_vec()).write_to(&mut output, b" ", b"\n").unwrap();
+    /// assert_eq!(written, 6);
+    /// assert_eq!(output, b"a b c\nab bc\nabc\n");
+    /// ```
+    pub fn write_to<W: Write>(
+        self,
+        writer: W,
+        delimiter: &[u8],
+        terminator: &[u8],
+    ) -> io::Result<usize> {
+        write_windows(self, writer, delimiter, terminator, |writer, window| {
+            writer.write_all(window)
+        })
+    }
+}
+
+impl<'a, S: Sliceable<char>> Gradient<'a, char, S> {
+    /// `write_to` streams every remaining window into `writer`, UTF-8
+    /// encoded, separated as byte windows are.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let mut output = Vec::new();
+    /// Gradient::new("né".chars().collect()).write_to(&mut output, "|", ";").unwrap();
+    /// assert_eq!(String::from_utf8(output).unwrap(), "n|é;né;");
+    /// ```
+    pub fn write_to<W: Write>(
+        self,
+        writer: W,
+        delimiter: &str,
+        terminator: &str,
+    ) -> io::Result<usize> {
+        let mut encoded = [0; 4];
+        write_windows(
+            self,
+            writer,
+            delimiter.as_bytes(),
+            terminator.as_bytes(),
+            |writer, window| {
+                window.iter().try_for_each(|char| {
+                    writer.write_all(char.encode_utf8(&mut encoded).as_bytes())
+                })
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_to_continues_where_iteration_stopped() {
+        let mut gradient = Gradient::with_max_width(b"abcd".to_vec(), Some(3));
+        gradient.nth(2);
+        let mut output = Vec::new();
+        assert_eq!(gradient.write_to(&mut output, b",", b"\n").unwrap(), 6);
+        assert_eq!(output, b"d\nab,bc,cd\nabc,bcd\n");
+        let mut empty = Vec::new();
+        assert_eq!(
+            Gradient::new(Vec::<u8>::new())
+                .write_to(&mut empty, b",", b"\n")
+                .unwrap(),
+            0
+        );
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn write_to_reports_writer_errors() {
+        struct Full;
+        impl Write for Full {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::WriteZero.into())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let error = Gradient::new(b"ab".to_vec())
+            .write_to(Full, b" ", b"\n")
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    }
+}
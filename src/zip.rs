@@ -0,0 +1,91 @@
+use crate::{Error, Gradient, Sliceable};
+use alloc::vec::Vec;
+
+/// `ZipWindows` pairs each window of a [Gradient] with the window at
+/// the same position of another input, see [Gradient::zip_windows].
+#[derive(Clone, Debug)]
+pub struct ZipWindows<'a, G, H, S = Vec<G>, T = Vec<H>> {
+    gradient: Gradient<'a, G, S>,
+    other: Gradient<'a, H, T>,
+}
+impl<'a, G: 'a, H: 'a, S: Sliceable<G>, T: Sliceable<H>> Iterator for ZipWindows<'a, G, H, S, T> {
+    type Item = (&'a [G], &'a [H]);
+
+    fn next(&mut self) -> Option<(&'a [G], &'a [H])> {
+        let window = self.gradient.next()?;
+        Some((window, self.other.slice(self.gradient.range())))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.gradient.size_hint()
+    }
+}
+
+impl<'a, G: 'a, S: Sliceable<G>> Gradient<'a, G, S> {
+    /// `zip_windows` pairs every window the [Gradient](Self) yields
+    /// with the window of `other` spanning the same width and start,
+    /// failing with [Error::LengthMismatch] unless both inputs are as
+    /// long as each other.
+    ///
+    /// Only the input of `other` is used: windows are yielded where
+    /// this gradient would yield them, up to its own `max_width`.
+    ///
+    /// ```
+    /// use gradient_slice::Gradient;
+    /// let changed = Gradient::from_slice(b"abc")
+    ///     .zip_windows(Gradient::from_slice(b"abd"))?
+    ///     .filter(|(before, after)| before != after)
+    ///     .count();
+    /// assert_eq!(changed, 3);
+    /// # Ok::<(), gradient_slice::Error>(())
+    /// ```
+    pub fn zip_windows<H: 'a, T: Sliceable<H>>(
+        self,
+        other: Gradient<'a, H, T>,
+    ) -> Result<ZipWindows<'a, G, H, S, T>, Error> {
+        if self.len() != other.len() {
+            return Err(Error::LengthMismatch {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
+        Ok(ZipWindows {
+            gradient: self,
+            other,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zip_windows_matches_zipping_gradients() {
+        let (before, after) = ([1, 2, 3, 4], ['a', 'b', 'c', 'd']);
+        for max_width in [None, Some(2)] {
+            let with = |input| Gradient {
+                max_width,
+                ..Gradient::from_slice(input)
+            };
+            let zipped = with(&before)
+                .zip_windows(Gradient::from_slice(&after))
+                .unwrap();
+            assert_eq!(zipped.size_hint().0, with(&before).total());
+            let expected = Iterator::zip(
+                with(&before),
+                Gradient {
+                    max_width,
+                    ..Gradient::from_slice(&after)
+                },
+            );
+            assert_eq!(zipped.collect::<Vec<_>>(), expected.collect::<Vec<_>>());
+        }
+        assert_eq!(
+            Gradient::from_slice(&before)
+                .zip_windows(Gradient::from_slice(&after[1..]))
+                .err(),
+            Some(Error::LengthMismatch { left: 4, right: 3 })
+        );
+    }
+}